@@ -0,0 +1,102 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// Integer type answers are summed in, with checked arithmetic
+pub trait Accumulator: Sized + Display {
+    fn zero() -> Self;
+    /// `None` if the value does not fit
+    fn from_i64(value: i64) -> Option<Self>;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_accumulator {
+    ($($integer:ty),*) => {
+        $(
+            impl Accumulator for $integer {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn from_i64(value: i64) -> Option<Self> {
+                    Self::try_from(value).ok()
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$integer>::checked_add(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$integer>::checked_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_accumulator!(i32, i64, i128);
+
+#[cfg(feature = "bigint")]
+impl Accumulator for num_bigint::BigInt {
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    fn from_i64(value: i64) -> Option<Self> {
+        Some(Self::from(value))
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        Some(self * other)
+    }
+}
+
+/// Accumulator used by the solvers
+#[cfg(feature = "bigint")]
+pub type Wide = num_bigint::BigInt;
+/// Accumulator used by the solvers, big integers with the `bigint` feature
+#[cfg(not(feature = "bigint"))]
+pub type Wide = i128;
+
+/// Item of the input whose value could not be accumulated
+#[derive(PartialEq, Eq, Debug)]
+pub struct Overflow {
+    /// Position of the item, in the order it was accumulated
+    pub index: usize,
+    /// The item as written in the input
+    pub item: String,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Overflow at item {}: {}", self.index, self.item)
+    }
+}
+
+impl Error for Overflow {}
+
+/// Sums the terms, `None` standing for a term that overflowed on its own.
+///
+/// # Errors
+///
+/// Will return an error, built by `describe` from the index of the term, if:
+/// - A term or the sum overflows
+pub fn checked_sum<A: Accumulator>(
+    terms: impl IntoIterator<Item = Option<A>>,
+    describe: impl Fn(usize) -> String,
+) -> Result<A, Overflow> {
+    let mut sum = A::zero();
+    for (index, term) in terms.into_iter().enumerate() {
+        sum = term
+            .and_then(|term| sum.checked_add(term))
+            .ok_or_else(|| Overflow {
+                index,
+                item: describe(index),
+            })?;
+    }
+    Ok(sum)
+}
@@ -0,0 +1,150 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Allocations made since the current phase started
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct AllocStats {
+    /// Reallocations count as allocations
+    pub nb_allocations: usize,
+    pub nb_allocated_bytes: usize,
+    /// Highest heap usage above the one at the start of the phase
+    pub peak_bytes: usize,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = if self.nb_allocations == 1 { "" } else { "s" };
+        write!(
+            f,
+            "{} allocation{plural}, {} allocated, {} peak",
+            self.nb_allocations,
+            format_bytes(self.nb_allocated_bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+/// Such as `512 B` or `1.50 KiB`
+pub fn format_bytes(nb_bytes: usize) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+    if nb_bytes < 1024 {
+        return format!("{nb_bytes} B");
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = nb_bytes as f64 / 1024.0;
+    let mut unit = UNITS[0];
+    for next_unit in &UNITS[1..] {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next_unit;
+    }
+    format!("{value:.2} {unit}")
+}
+
+/// The system allocator, counting what goes through it.
+///
+/// Installed as the `#[global_allocator]` of a binary, it measures the heap
+/// usage of everything the binary runs. Phases are global: allocations from
+/// all the threads are counted in the current one.
+pub struct CountingAllocator {
+    nb_allocations: AtomicUsize,
+    nb_allocated_bytes: AtomicUsize,
+    current_bytes: AtomicUsize,
+    /// Heap usage when the phase started
+    start_bytes: AtomicUsize,
+    peak_bytes: AtomicUsize,
+}
+
+impl CountingAllocator {
+    pub const fn new() -> Self {
+        Self {
+            nb_allocations: AtomicUsize::new(0),
+            nb_allocated_bytes: AtomicUsize::new(0),
+            current_bytes: AtomicUsize::new(0),
+            start_bytes: AtomicUsize::new(0),
+            peak_bytes: AtomicUsize::new(0),
+        }
+    }
+
+    /// Counts from zero again, the peak being measured from the current usage
+    pub fn start_phase(&self) {
+        let current_bytes = self.current_bytes.load(Ordering::Relaxed);
+        self.nb_allocations.store(0, Ordering::Relaxed);
+        self.nb_allocated_bytes.store(0, Ordering::Relaxed);
+        self.start_bytes.store(current_bytes, Ordering::Relaxed);
+        self.peak_bytes.store(current_bytes, Ordering::Relaxed);
+    }
+
+    pub fn get_stats(&self) -> AllocStats {
+        let start_bytes = self.start_bytes.load(Ordering::Relaxed);
+        AllocStats {
+            nb_allocations: self.nb_allocations.load(Ordering::Relaxed),
+            nb_allocated_bytes: self.nb_allocated_bytes.load(Ordering::Relaxed),
+            peak_bytes: self
+                .peak_bytes
+                .load(Ordering::Relaxed)
+                .saturating_sub(start_bytes),
+        }
+    }
+
+    /// Runs `callback` in a phase of its own
+    pub fn measure<R>(&self, callback: impl FnOnce() -> R) -> (R, AllocStats) {
+        self.start_phase();
+        let result = callback();
+        (result, self.get_stats())
+    }
+
+    fn record_allocation(&self, size: usize) {
+        self.nb_allocations.fetch_add(1, Ordering::Relaxed);
+        self.nb_allocated_bytes.fetch_add(size, Ordering::Relaxed);
+        let current_bytes = self.current_bytes.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak_bytes.fetch_max(current_bytes, Ordering::Relaxed);
+    }
+
+    fn record_deallocation(&self, size: usize) {
+        self.current_bytes.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+impl Default for CountingAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Only bookkeeping is added around the system allocator, which does the
+// allocations
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            self.record_allocation(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            self.record_allocation(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        self.record_deallocation(layout.size());
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            self.record_deallocation(layout.size());
+            self.record_allocation(new_size);
+        }
+        new_pointer
+    }
+}
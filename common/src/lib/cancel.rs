@@ -0,0 +1,64 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// Asks a running solver to stop.
+///
+/// The token is given to the `Executor` of the solver, which checks it before
+/// each item. A cancelled solver unwinds with `Cancelled`, to be caught by
+/// `run_cancellable`.
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    is_cancelled: Arc<AtomicBool>,
+}
+
+/// Unwinding payload of a cancelled solver
+#[derive(Debug)]
+pub struct Cancelled;
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.is_cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Cancels from another thread once `delay` has passed
+    pub fn cancel_after(&self, delay: Duration) {
+        let token = self.clone();
+        thread::spawn(move || {
+            thread::sleep(delay);
+            token.cancel();
+        });
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.is_cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Unwinds with `Cancelled` if the token was cancelled
+    pub fn check(&self) {
+        if self.is_cancelled() {
+            panic::resume_unwind(Box::new(Cancelled));
+        }
+    }
+}
+
+/// Runs `callback`, turning its cancellation into an error. Other panics go on
+/// unwinding.
+///
+/// # Errors
+///
+/// Will return an error if the callback was cancelled
+pub fn run_cancellable<R>(callback: impl FnOnce() -> R) -> Result<R, Cancelled> {
+    panic::catch_unwind(AssertUnwindSafe(callback)).map_err(|payload| {
+        match payload.downcast::<Cancelled>() {
+            Ok(cancelled) => *cancelled,
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    })
+}
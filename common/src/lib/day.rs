@@ -0,0 +1,48 @@
+use std::time::Instant;
+
+use tracing::info_span;
+
+use crate::exec::Executor;
+use crate::input::read_input_from_args_file;
+
+/// A puzzle day: the input is parsed once, and both parts are solved from it.
+///
+/// The parsed model may borrow from the input, to avoid copying it.
+pub trait Day {
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Self::Parsed<'_>;
+    fn part1(parsed: &Self::Parsed<'_>, executor: &Executor) -> String;
+    fn part2(parsed: &Self::Parsed<'_>, executor: &Executor) -> String;
+}
+
+pub fn solve_part1<D: Day>(input: &str) -> String {
+    D::part1(&D::parse(input), &Executor::default())
+}
+
+pub fn solve_part2<D: Day>(input: &str) -> String {
+    D::part2(&D::parse(input), &Executor::default())
+}
+
+/// # Panics
+///
+/// Will panic if:
+/// - No file path provided
+/// - File not found
+/// - Issue while reading the file
+pub fn run_from_args_file<D: Day>() {
+    let now = Instant::now();
+
+    let executor = Executor::default();
+    let input = read_input_from_args_file();
+    let parsed = info_span!("parse").in_scope(|| D::parse(input.as_str()));
+    println!("Parsed in: {:.2?}", now.elapsed());
+
+    let part_1 = info_span!("part", part = 1).in_scope(|| D::part1(&parsed, &executor));
+    println!("The solution of part 1 is `{part_1}`");
+    println!("Solved in: {:.2?}", now.elapsed());
+
+    let part_2 = info_span!("part", part = 2).in_scope(|| D::part2(&parsed, &executor));
+    println!("The solution of part 2 is `{part_2}`");
+    println!("Solved in: {:.2?}", now.elapsed());
+}
@@ -0,0 +1,185 @@
+use std::iter::Sum;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use crate::cancel::CancelToken;
+use crate::progress::{Progress, ProgressHook};
+
+/// Runs independent per-item work, either on the current thread or on a
+/// rayon thread pool.
+///
+/// Every method gives the same result whatever the number of threads:
+/// `map` keeps the items order, `count` and `sum` only combine integers.
+///
+/// The items are counted in the progress of the current phase, which is given
+/// to the progress hook after each item. With a cancel token, the token is
+/// checked before each item.
+pub struct Executor {
+    backend: Backend,
+    cancel_token: Option<CancelToken>,
+    progress_hook: Option<Arc<dyn ProgressHook>>,
+    nb_items: AtomicUsize,
+    nb_done: AtomicUsize,
+}
+
+enum Backend {
+    Sequential,
+    /// Rayon's global pool, with one thread per core
+    Global,
+    Pool(ThreadPool),
+}
+
+impl Executor {
+    /// `0` uses one thread per core, `1` runs everything on the current thread
+    ///
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - The thread pool cannot be created
+    pub fn new(nb_threads: usize) -> Self {
+        let backend = match nb_threads {
+            0 => Backend::Global,
+            1 => Backend::Sequential,
+            _ => Backend::Pool(
+                ThreadPoolBuilder::new()
+                    .num_threads(nb_threads)
+                    .build()
+                    .expect("Cannot create the thread pool"),
+            ),
+        };
+        Self {
+            backend,
+            cancel_token: None,
+            progress_hook: None,
+            nb_items: AtomicUsize::new(0),
+            nb_done: AtomicUsize::new(0),
+        }
+    }
+
+    #[must_use]
+    pub fn with_progress_hook(mut self, progress_hook: Arc<dyn ProgressHook>) -> Self {
+        self.progress_hook = Some(progress_hook);
+        self
+    }
+
+    #[must_use]
+    pub fn with_cancel_token(mut self, cancel_token: CancelToken) -> Self {
+        self.cancel_token = Some(cancel_token);
+        self
+    }
+
+    /// Counts the items from zero again, solvers name their long phases so that
+    /// their progress can be followed
+    pub fn start_phase(&self, phase: &str) {
+        self.nb_items.store(0, Ordering::Relaxed);
+        self.nb_done.store(0, Ordering::Relaxed);
+        if let Some(progress_hook) = &self.progress_hook {
+            progress_hook.start_phase(phase);
+        }
+    }
+
+    pub fn get_progress(&self) -> Progress {
+        Progress {
+            nb_done: self.nb_done.load(Ordering::Relaxed),
+            nb_items: self.nb_items.load(Ordering::Relaxed),
+        }
+    }
+
+    /// For the loops not going through the executor: unwinds if the cancel
+    /// token was cancelled
+    pub fn check_cancelled(&self) {
+        if let Some(cancel_token) = &self.cancel_token {
+            cancel_token.check();
+        }
+    }
+
+    pub fn sequential() -> Self {
+        Self::new(1)
+    }
+
+    pub fn get_nb_threads(&self) -> usize {
+        match &self.backend {
+            Backend::Sequential => 1,
+            Backend::Global => rayon::current_num_threads(),
+            Backend::Pool(pool) => pool.current_num_threads(),
+        }
+    }
+
+    fn install<R: Send>(&self, callback: impl FnOnce() -> R + Send) -> R {
+        match &self.backend {
+            Backend::Pool(pool) => pool.install(callback),
+            Backend::Sequential | Backend::Global => callback(),
+        }
+    }
+
+    fn is_sequential(&self) -> bool {
+        matches!(self.backend, Backend::Sequential)
+    }
+
+    pub fn map<T, R, F>(&self, items: &[T], callback: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync + Send,
+    {
+        self.add_items(items.len());
+        let callback = |item: &T| self.run_item(item, &callback);
+        if self.is_sequential() {
+            return items.iter().map(callback).collect();
+        }
+        self.install(|| items.par_iter().map(callback).collect())
+    }
+
+    pub fn count<T, F>(&self, items: &[T], predicate: F) -> usize
+    where
+        T: Sync,
+        F: Fn(&T) -> bool + Sync + Send,
+    {
+        self.add_items(items.len());
+        let predicate = |item: &&T| self.run_item(*item, &predicate);
+        if self.is_sequential() {
+            return items.iter().filter(predicate).count();
+        }
+        self.install(|| items.par_iter().filter(predicate).count())
+    }
+
+    pub fn sum<T, R, F>(&self, items: &[T], callback: F) -> R
+    where
+        T: Sync,
+        R: Send + Sum<R>,
+        F: Fn(&T) -> R + Sync + Send,
+    {
+        self.add_items(items.len());
+        let callback = |item: &T| self.run_item(item, &callback);
+        if self.is_sequential() {
+            return items.iter().map(callback).sum();
+        }
+        self.install(|| items.par_iter().map(callback).sum())
+    }
+
+    fn add_items(&self, nb_items: usize) {
+        self.nb_items.fetch_add(nb_items, Ordering::Relaxed);
+    }
+
+    fn run_item<T, R>(&self, item: &T, callback: impl Fn(&T) -> R) -> R {
+        self.check_cancelled();
+        let result = callback(item);
+        let nb_done = self.nb_done.fetch_add(1, Ordering::Relaxed) + 1;
+        if let Some(progress_hook) = &self.progress_hook {
+            progress_hook.update(Progress {
+                nb_done,
+                nb_items: self.nb_items.load(Ordering::Relaxed),
+            });
+        }
+        result
+    }
+}
+
+impl Default for Executor {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+
+/// Rectangular grid of ASCII cells, indexed directly in the input bytes.
+///
+/// Rows are the input lines, so the line ending (`\n` or `\r\n`) is skipped
+/// with a stride rather than by copying each row.
+pub struct ByteGrid<'a> {
+    bytes: &'a [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+impl<'a> ByteGrid<'a> {
+    /// Trailing empty lines are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - The lines do not all have the same length
+    /// - The lines do not all use the same line ending
+    pub fn new(input: &'a str) -> Self {
+        let input = input.trim_end_matches(['\n', '\r']);
+        let bytes = input.as_bytes();
+        let width = input.lines().next().map_or(0, str::len);
+        let stride = match bytes.get(width) {
+            Some(b'\r') => width + 2,
+            _ => width + 1,
+        };
+
+        let mut height = 0;
+        for line in input.split_inclusive('\n') {
+            let cells = line.trim_end_matches(['\n', '\r']);
+            assert!(
+                cells.len() == width,
+                "Line {height} has {} cells instead of {width}",
+                cells.len()
+            );
+            assert!(
+                line.len() == stride || height * stride + line.len() == bytes.len(),
+                "Line {height} does not use the same line ending as the first one"
+            );
+            height += 1;
+        }
+
+        Self {
+            bytes,
+            width,
+            height,
+            stride,
+        }
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns `None` outside of the grid
+    pub fn get(&self, x: i32, y: i32) -> Option<u8> {
+        let x = usize::try_from(x).ok()?;
+        let y = usize::try_from(y).ok()?;
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        Some(self.bytes[y * self.stride + x])
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - `y` is outside of the grid
+    pub fn row(&self, y: usize) -> &'a [u8] {
+        assert!(y < self.height, "Row {y} is outside of the grid");
+        let start = y * self.stride;
+        &self.bytes[start..start + self.width]
+    }
+}
+
+/// The rows, as strings
+impl fmt::Debug for ByteGrid<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.height).map(|y| String::from_utf8_lossy(self.row(y))))
+            .finish()
+    }
+}
@@ -0,0 +1,78 @@
+use std::env;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use memmap2::Mmap;
+
+/// Puzzle input, memory-mapped from a file or owned.
+///
+/// Solvers only get `&str` views on it: lines are slices of the mapped bytes,
+/// nothing is copied. The content is checked to be UTF-8 once, when opened.
+pub struct Input {
+    source: Source,
+}
+
+enum Source {
+    Mapped(Mmap),
+    Owned(String),
+}
+
+impl Input {
+    /// # Errors
+    ///
+    /// Will return an error if:
+    /// - The file cannot be opened or mapped
+    /// - The file is not valid UTF-8
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let file = File::open(path)?;
+        if file.metadata()?.len() == 0 {
+            // Empty mappings are rejected by some platforms
+            return Ok(Self::from(String::new()));
+        }
+
+        // SAFETY: the file is only read. As with any mapping, truncating or
+        // rewriting it while it is being solved is not supported.
+        let mmap = unsafe { Mmap::map(&file)? };
+        std::str::from_utf8(&mmap)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        Ok(Self {
+            source: Source::Mapped(mmap),
+        })
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match &self.source {
+            Source::Mapped(mmap) => mmap,
+            Source::Owned(string) => string.as_bytes(),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match &self.source {
+            // SAFETY: checked to be UTF-8 in `open`
+            Source::Mapped(mmap) => unsafe { std::str::from_utf8_unchecked(mmap) },
+            Source::Owned(string) => string,
+        }
+    }
+}
+
+impl From<String> for Input {
+    fn from(value: String) -> Self {
+        Self {
+            source: Source::Owned(value),
+        }
+    }
+}
+
+/// # Panics
+///
+/// Will panic if:
+/// - No file path provided
+/// - File not found
+/// - Issue while reading the file
+pub fn read_input_from_args_file() -> Input {
+    let args: Vec<String> = env::args().collect();
+    let path_str = args.get(1).expect("No file path provided");
+    Input::open(Path::new(path_str)).unwrap_or_else(|_| panic!("file not found {path_str}"))
+}
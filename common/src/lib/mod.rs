@@ -0,0 +1,13 @@
+#![warn(clippy::pedantic)]
+#![allow(clippy::must_use_candidate)]
+
+pub mod accumulator;
+pub mod alloc;
+pub mod cancel;
+pub mod day;
+pub mod exec;
+pub mod grid;
+pub mod input;
+pub mod progress;
+#[cfg(feature = "dev")]
+pub mod testing;
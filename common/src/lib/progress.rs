@@ -0,0 +1,22 @@
+/// Items handed to the executor since the current phase started
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Progress {
+    pub nb_done: usize,
+    pub nb_items: usize,
+}
+
+/// Receives the progress of a solver, as ticked by its `Executor`.
+///
+/// Methods are called from the threads running the items, an implementation
+/// has to keep them cheap, for example by only drawing from time to time.
+pub trait ProgressHook: Send + Sync {
+    /// Such as `parsing` or `testing obstructions`, the items are counted
+    /// from zero again
+    fn start_phase(&self, phase: &str);
+
+    /// Called after each item
+    fn update(&self, progress: Progress);
+
+    /// The solver is done, or was stopped
+    fn finish(&self) {}
+}
@@ -0,0 +1,23 @@
+pub use insta;
+
+/// Debug snapshot named after `$name` alone. Snapshot test files are built
+/// both on their own and as a module of `tests/mod.rs`, which would otherwise
+/// give them two different names.
+#[macro_export]
+macro_rules! assert_named_snapshot {
+    ($name:literal, $value:expr) => {
+        $crate::testing::insta::with_settings!({ prepend_module_to_snapshot => false }, {
+            $crate::testing::insta::assert_debug_snapshot!($name, $value);
+        })
+    };
+}
+
+/// Same as `assert_named_snapshot!`, for text snapshotted as it is displayed
+#[macro_export]
+macro_rules! assert_named_text_snapshot {
+    ($name:literal, $value:expr) => {
+        $crate::testing::insta::with_settings!({ prepend_module_to_snapshot => false }, {
+            $crate::testing::insta::assert_snapshot!($name, $value);
+        })
+    };
+}
@@ -0,0 +1,63 @@
+mod checked_sum {
+    use aoc2024_common::accumulator::{checked_sum, Accumulator, Overflow};
+
+    #[test]
+    fn no_overflow() {
+        // Given
+        let terms = [1, 2, 3].map(i64::from_i64);
+
+        // When
+        let result = checked_sum(terms, |index| index.to_string());
+
+        // Then
+        assert_eq!(result, Ok(6));
+    }
+
+    #[test]
+    fn sum_overflow() {
+        // Given
+        let terms = [i64::from(i32::MAX), 1, 1].map(i32::from_i64);
+
+        // When
+        let result = checked_sum(terms, |index| format!("term {index}"));
+
+        // Then
+        assert_eq!(
+            result,
+            Err(Overflow {
+                index: 1,
+                item: String::from("term 1")
+            })
+        );
+    }
+
+    #[test]
+    fn term_overflow() {
+        // Given
+        let terms = [1, i64::MAX].map(i32::from_i64);
+
+        // When
+        let result = checked_sum(terms, |index| format!("term {index}"));
+
+        // Then
+        assert_eq!(
+            result,
+            Err(Overflow {
+                index: 1,
+                item: String::from("term 1")
+            })
+        );
+    }
+
+    #[test]
+    fn wider_accumulator() {
+        // Given
+        let terms = [i64::MAX, i64::MAX].map(i128::from_i64);
+
+        // When
+        let result = checked_sum(terms, |index| index.to_string());
+
+        // Then
+        assert_eq!(result, Ok(2 * i128::from(i64::MAX)));
+    }
+}
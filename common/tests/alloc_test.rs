@@ -0,0 +1,100 @@
+mod counting_allocator {
+    use std::alloc::{GlobalAlloc, Layout};
+
+    use aoc2024_common::alloc::{AllocStats, CountingAllocator};
+
+    #[test]
+    fn allocations_and_peak() {
+        // Given
+        let allocator = CountingAllocator::new();
+        let large = Layout::from_size_align(100, 8).unwrap();
+        let small = Layout::from_size_align(50, 8).unwrap();
+
+        // When
+        let stats = unsafe {
+            let first = allocator.alloc(large);
+            let second = allocator.alloc(small);
+            allocator.dealloc(first, large);
+            let third = allocator.alloc(small);
+            allocator.dealloc(second, small);
+            allocator.dealloc(third, small);
+            allocator.get_stats()
+        };
+
+        // Then
+        assert_eq!(
+            stats,
+            AllocStats {
+                nb_allocations: 3,
+                nb_allocated_bytes: 200,
+                peak_bytes: 150,
+            }
+        );
+    }
+
+    #[test]
+    fn reallocation() {
+        // Given
+        let allocator = CountingAllocator::new();
+        let layout = Layout::from_size_align(16, 8).unwrap();
+
+        // When
+        let stats = unsafe {
+            let pointer = allocator.alloc(layout);
+            let pointer = allocator.realloc(pointer, layout, 64);
+            allocator.dealloc(pointer, Layout::from_size_align(64, 8).unwrap());
+            allocator.get_stats()
+        };
+
+        // Then
+        assert_eq!(
+            stats,
+            AllocStats {
+                nb_allocations: 2,
+                nb_allocated_bytes: 80,
+                peak_bytes: 64,
+            }
+        );
+    }
+
+    #[test]
+    fn peak_from_the_phase_start() {
+        // Given
+        let allocator = CountingAllocator::new();
+        let layout = Layout::from_size_align(1000, 8).unwrap();
+        let kept = unsafe { allocator.alloc(layout) };
+
+        // When
+        let ((), stats) = allocator.measure(|| unsafe {
+            let pointer = allocator.alloc(layout);
+            allocator.dealloc(pointer, layout);
+        });
+
+        // Then
+        assert_eq!(
+            stats,
+            AllocStats {
+                nb_allocations: 1,
+                nb_allocated_bytes: 1000,
+                peak_bytes: 1000,
+            }
+        );
+        unsafe { allocator.dealloc(kept, layout) };
+    }
+}
+
+mod format_bytes {
+    use aoc2024_common::alloc::format_bytes;
+
+    #[test]
+    fn units() {
+        // When
+        let result = [0, 1023, 1536, 5 << 20, 3 << 30].map(format_bytes);
+
+        // Then
+        assert_eq!(
+            result,
+            ["0 B", "1023 B", "1.50 KiB", "5.00 MiB", "3.00 GiB"].map(String::from)
+        );
+    }
+}
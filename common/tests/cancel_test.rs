@@ -0,0 +1,95 @@
+mod run_cancellable {
+    use aoc2024_common::cancel::{run_cancellable, CancelToken};
+    use aoc2024_common::exec::Executor;
+    use aoc2024_common::progress::Progress;
+
+    #[test]
+    fn not_cancelled() {
+        // Given
+        let cancel_token = CancelToken::new();
+        let executor = Executor::sequential().with_cancel_token(cancel_token.clone());
+        let items: Vec<u64> = (0..100).collect();
+
+        // When
+        let result = run_cancellable(|| executor.sum(&items, |&item| item));
+
+        // Then
+        assert_eq!(result.unwrap(), 4950);
+        assert_eq!(
+            executor.get_progress(),
+            Progress {
+                nb_done: 100,
+                nb_items: 100
+            }
+        );
+    }
+
+    #[test]
+    fn cancelled_during_the_items() {
+        // Given
+        let cancel_token = CancelToken::new();
+        let executor = Executor::sequential().with_cancel_token(cancel_token.clone());
+        let items: Vec<u64> = (0..100).collect();
+
+        // When
+        let result = run_cancellable(|| {
+            executor.count(&items, |&item| {
+                if item == 41 {
+                    cancel_token.cancel();
+                }
+                true
+            })
+        });
+
+        // Then
+        assert!(result.is_err());
+        assert_eq!(
+            executor.get_progress(),
+            Progress {
+                nb_done: 42,
+                nb_items: 100
+            }
+        );
+    }
+
+    #[test]
+    fn cancelled_on_a_thread_pool() {
+        // Given
+        let cancel_token = CancelToken::new();
+        cancel_token.cancel();
+        let executor = Executor::new(4).with_cancel_token(cancel_token.clone());
+        let items: Vec<u64> = (0..100).collect();
+
+        // When
+        let result = run_cancellable(|| executor.map(&items, |&item| item * 2));
+
+        // Then
+        assert!(result.is_err());
+        assert_eq!(executor.get_progress().nb_done, 0);
+    }
+
+    #[test]
+    fn inner_loop_check() {
+        // Given
+        let cancel_token = CancelToken::new();
+        cancel_token.cancel();
+        let executor = Executor::sequential().with_cancel_token(cancel_token);
+
+        // When
+        let result = run_cancellable(|| {
+            for _ in 0..10 {
+                executor.check_cancelled();
+            }
+        });
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Not a cancellation")]
+    fn other_panics_go_on() {
+        // When
+        let _ = run_cancellable(|| panic!("Not a cancellation"));
+    }
+}
@@ -0,0 +1,58 @@
+use aoc2024_common::day::Day;
+use aoc2024_common::exec::Executor;
+
+struct LineCount;
+
+impl Day for LineCount {
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.lines().collect()
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>, _executor: &Executor) -> String {
+        parsed.len().to_string()
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>, _executor: &Executor) -> String {
+        parsed
+            .iter()
+            .map(|line| line.len())
+            .sum::<usize>()
+            .to_string()
+    }
+}
+
+mod solve_part1 {
+    use super::LineCount;
+    use aoc2024_common::day::solve_part1;
+
+    #[test]
+    fn parses_then_solves() {
+        // Given
+        let input = "ab\ncde\n";
+
+        // When
+        let result = solve_part1::<LineCount>(input);
+
+        // Then
+        assert_eq!(result, "2");
+    }
+}
+
+mod solve_part2 {
+    use super::LineCount;
+    use aoc2024_common::day::solve_part2;
+
+    #[test]
+    fn parses_then_solves() {
+        // Given
+        let input = "ab\ncde\n";
+
+        // When
+        let result = solve_part2::<LineCount>(input);
+
+        // Then
+        assert_eq!(result, "5");
+    }
+}
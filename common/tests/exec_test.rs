@@ -0,0 +1,81 @@
+mod map {
+    use aoc2024_common::exec::Executor;
+
+    #[test]
+    fn keeps_items_order_whatever_the_threads() {
+        // Given
+        let items: Vec<u64> = (0..10_000).collect();
+
+        // When
+        let sequential = Executor::new(1).map(&items, |x| x * 3);
+        let parallel = Executor::new(4).map(&items, |x| x * 3);
+
+        // Then
+        assert_eq!(sequential, parallel);
+        assert_eq!(sequential[9_999], 29_997);
+    }
+}
+
+mod count {
+    use aoc2024_common::exec::Executor;
+
+    #[test]
+    fn same_count_whatever_the_threads() {
+        // Given
+        let items: Vec<u64> = (0..10_000).collect();
+
+        // When
+        let sequential = Executor::new(1).count(&items, |x| x % 7 == 0);
+        let parallel = Executor::new(4).count(&items, |x| x % 7 == 0);
+
+        // Then
+        assert_eq!(sequential, 1_429);
+        assert_eq!(parallel, 1_429);
+    }
+}
+
+mod sum {
+    use aoc2024_common::exec::Executor;
+
+    #[test]
+    fn same_sum_whatever_the_threads() {
+        // Given
+        let items: Vec<u64> = (0..10_000).collect();
+
+        // When
+        let sequential: u64 = Executor::new(1).sum(&items, |x| *x);
+        let parallel: u64 = Executor::new(3).sum(&items, |x| *x);
+
+        // Then
+        assert_eq!(sequential, 49_995_000);
+        assert_eq!(parallel, 49_995_000);
+    }
+}
+
+mod get_nb_threads {
+    use aoc2024_common::exec::Executor;
+
+    #[test]
+    fn sequential_has_one_thread() {
+        // Given
+        let executor = Executor::sequential();
+
+        // When
+        let result = executor.get_nb_threads();
+
+        // Then
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn pool_has_requested_threads() {
+        // Given
+        let executor = Executor::new(3);
+
+        // When
+        let result = executor.get_nb_threads();
+
+        // Then
+        assert_eq!(result, 3);
+    }
+}
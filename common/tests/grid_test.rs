@@ -0,0 +1,61 @@
+mod get {
+    use aoc2024_common::grid::ByteGrid;
+
+    #[test]
+    fn cells_and_outside() {
+        // Given
+        let grid = ByteGrid::new("abc\ndef\n");
+
+        // When
+        let result = [
+            grid.get(0, 0),
+            grid.get(2, 1),
+            grid.get(3, 0),
+            grid.get(0, -1),
+        ];
+
+        // Then
+        assert_eq!(result, [Some(b'a'), Some(b'f'), None, None]);
+        assert_eq!((grid.get_width(), grid.get_height()), (3, 2));
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        // Given
+        let grid = ByteGrid::new("abc\r\ndef\r\nghi");
+
+        // When
+        let result = grid.get(1, 2);
+
+        // Then
+        assert_eq!(result, Some(b'h'));
+        assert_eq!(grid.get_height(), 3);
+    }
+}
+
+mod row {
+    use aoc2024_common::grid::ByteGrid;
+
+    #[test]
+    fn without_line_ending() {
+        // Given
+        let grid = ByteGrid::new("abc\ndef\n\n");
+
+        // When
+        let result = grid.row(1);
+
+        // Then
+        assert_eq!(result, b"def");
+        assert_eq!(grid.get_height(), 2);
+    }
+}
+
+mod new {
+    use aoc2024_common::grid::ByteGrid;
+
+    #[test]
+    #[should_panic(expected = "Line 1 has 2 cells instead of 3")]
+    fn lines_of_different_lengths() {
+        ByteGrid::new("abc\nde\n");
+    }
+}
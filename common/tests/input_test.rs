@@ -0,0 +1,51 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn write_temp_file(name: &str, content: &[u8]) -> PathBuf {
+    let path = env::temp_dir().join(format!("aoc2024-input-test-{}-{name}", std::process::id()));
+    fs::write(&path, content).unwrap();
+    path
+}
+
+mod open {
+    use super::write_temp_file;
+    use aoc2024_common::input::Input;
+
+    #[test]
+    fn maps_file_content() {
+        // Given
+        let path = write_temp_file("content", b"3   4\n4   3\n");
+
+        // When
+        let input = Input::open(&path).unwrap();
+
+        // Then
+        assert_eq!(input.as_str(), "3   4\n4   3\n");
+        assert_eq!(input.as_str().lines().count(), 2);
+    }
+
+    #[test]
+    fn empty_file() {
+        // Given
+        let path = write_temp_file("empty", b"");
+
+        // When
+        let input = Input::open(&path).unwrap();
+
+        // Then
+        assert_eq!(input.as_str(), "");
+    }
+
+    #[test]
+    fn invalid_utf8_is_rejected() {
+        // Given
+        let path = write_temp_file("invalid", &[b'a', 0xFF, b'\n']);
+
+        // When
+        let result = Input::open(&path);
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,9 @@
+#![warn(clippy::pedantic)]
+
+pub mod accumulator_test;
+pub mod alloc_test;
+pub mod cancel_test;
+pub mod day_test;
+pub mod exec_test;
+pub mod grid_test;
+pub mod input_test;
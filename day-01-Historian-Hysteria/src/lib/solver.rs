@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use aoc2024_common::accumulator::{checked_sum, Accumulator, Overflow, Wide};
+use aoc2024_common::day::Day;
+use aoc2024_common::exec::Executor;
+use tracing::instrument;
+
+/// # Panics
+///
+/// Will panic if:
+/// - The string does not contain two numbers separated by spaces
+#[allow(clippy::double_ended_iterator_last)]
+pub fn extract_numbers(line: &str) -> (i32, i32) {
+    let mut words = line.split(' ');
+    let first_number: i32 = words.next().unwrap().parse().unwrap();
+    let last_number: i32 = words.last().unwrap().parse().unwrap();
+    (first_number, last_number)
+}
+
+fn get_distance(pair: (i32, i32)) -> i64 {
+    let (a, b) = pair;
+    i64::from(a.abs_diff(b))
+}
+
+fn list_to_occurrences(list: &[i32]) -> HashMap<i32, i32> {
+    let mut occurrences = HashMap::new();
+    for &number in list {
+        let count = occurrences.entry(number).or_insert(0);
+        *count += 1;
+    }
+    occurrences
+}
+
+#[derive(Debug)]
+pub struct LocationLists {
+    left_list: Vec<i32>,
+    right_list: Vec<i32>,
+}
+
+impl LocationLists {
+    /// Numbers of both lists paired once sorted, smallest first
+    pub fn get_sorted_pairs(&self) -> Vec<(i32, i32)> {
+        let mut left_list = self.left_list.clone();
+        let mut right_list = self.right_list.clone();
+        left_list.sort_unstable();
+        right_list.sort_unstable();
+        left_list.into_iter().zip(right_list).collect()
+    }
+
+    /// Sum of the distances between the sorted lists
+    ///
+    /// # Errors
+    ///
+    /// Will return an error, with the pair of sorted numbers, if:
+    /// - The sum or a distance overflows `A`
+    #[instrument(level = "debug", skip_all)]
+    pub fn get_total_distance<A: Accumulator>(&self) -> Result<A, Overflow> {
+        let pairs = self.get_sorted_pairs();
+        checked_sum(
+            pairs.iter().map(|&pair| A::from_i64(get_distance(pair))),
+            |index| {
+                let (left, right) = pairs[index];
+                format!("{left} and {right}")
+            },
+        )
+    }
+
+    /// Sum of the left numbers, each multiplied by its count in the right list
+    ///
+    /// # Errors
+    ///
+    /// Will return an error, with the line of the left number, if:
+    /// - The sum or a score overflows `A`
+    #[instrument(level = "debug", skip_all)]
+    pub fn get_similarity_score<A: Accumulator>(&self) -> Result<A, Overflow> {
+        let right_list = list_to_occurrences(&self.right_list);
+        let count = |number| i64::from(*right_list.get(number).unwrap_or(&0));
+        checked_sum(
+            self.left_list
+                .iter()
+                .map(|number| A::from_i64(i64::from(*number) * count(number))),
+            |index| {
+                let number = &self.left_list[index];
+                format!("{number} seen {} times", count(number))
+            },
+        )
+    }
+}
+
+pub struct Day01;
+
+impl Day for Day01 {
+    type Parsed<'a> = LocationLists;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        let (left_list, right_list) = input.lines().map(extract_numbers).unzip();
+        LocationLists {
+            left_list,
+            right_list,
+        }
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>, _executor: &Executor) -> String {
+        parsed
+            .get_total_distance::<Wide>()
+            .unwrap_or_else(|overflow| panic!("{overflow}"))
+            .to_string()
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>, _executor: &Executor) -> String {
+        parsed
+            .get_similarity_score::<Wide>()
+            .unwrap_or_else(|overflow| panic!("{overflow}"))
+            .to_string()
+    }
+}
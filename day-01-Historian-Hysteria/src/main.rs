@@ -0,0 +1,8 @@
+#![warn(clippy::pedantic)]
+
+use aoc2024_common::day::run_from_args_file;
+use aoc2024_day_01::solver::Day01;
+
+fn main() {
+    run_from_args_file::<Day01>();
+}
@@ -0,0 +1,40 @@
+const PUZZLE_SUBJECT_EXAMPLE: &[&str] = &["3   4", "4   3", "2   5", "1   3", "3   9", "3   3"];
+
+mod parse {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::assert_named_snapshot;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_01::solver::Day01;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+
+        // When
+        let result = Day01::parse(&input);
+
+        // Then
+        assert_named_snapshot!("parse", result);
+    }
+}
+
+mod get_sorted_pairs {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::assert_named_snapshot;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_01::solver::Day01;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let location_lists = Day01::parse(&input);
+
+        // When
+        let result = location_lists.get_sorted_pairs();
+
+        // Then
+        assert_named_snapshot!("sorted_pairs", result);
+    }
+}
@@ -0,0 +1,119 @@
+mod extract_numbers {
+    use aoc2024_day_01::solver::extract_numbers;
+
+    #[test]
+    fn only_digits_3_4() {
+        // When
+        let input = "3   4";
+        // When
+        let result = extract_numbers(input);
+        // Then
+        assert_eq!(result, (3, 4));
+    }
+
+    #[test]
+    fn numbers_85540_67702() {
+        // When
+        let input = "85540   67702";
+        // When
+        let result = extract_numbers(input);
+        // Then
+        assert_eq!(result, (85540, 67702));
+    }
+}
+
+mod part1 {
+    use aoc2024_common::day::solve_part1;
+    use aoc2024_day_01::solver::Day01;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // When
+        let input = &["3   4", "4   3", "2   5", "1   3", "3   9", "3   3"];
+
+        // When
+        let result = solve_part1::<Day01>(&input.join("\n"));
+
+        // Then
+        assert_eq!(result, "11");
+    }
+}
+
+mod part2 {
+    use aoc2024_common::day::solve_part2;
+    use aoc2024_day_01::solver::Day01;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // When
+        let input = &["3   4", "4   3", "2   5", "1   3", "3   9", "3   3"];
+
+        // When
+        let result = solve_part2::<Day01>(&input.join("\n"));
+
+        // Then
+        assert_eq!(result, "31");
+    }
+}
+
+mod get_total_distance {
+    use aoc2024_common::accumulator::Overflow;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_01::solver::Day01;
+
+    const INPUT: &str = "1   2\n2000000000   -2000000000";
+
+    #[test]
+    fn overflowing_distance() {
+        // Given
+        let location_lists = Day01::parse(INPUT);
+
+        // When
+        let result = location_lists.get_total_distance::<i32>();
+
+        // Then
+        assert_eq!(
+            result,
+            Err(Overflow {
+                index: 1,
+                item: String::from("2000000000 and 2")
+            })
+        );
+    }
+
+    #[test]
+    fn wider_accumulator() {
+        // Given
+        let location_lists = Day01::parse(INPUT);
+
+        // When
+        let result = location_lists.get_total_distance::<i64>();
+
+        // Then
+        assert_eq!(result, Ok(2_000_000_001 + 1_999_999_998));
+    }
+}
+
+mod get_similarity_score {
+    use aoc2024_common::accumulator::Overflow;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_01::solver::Day01;
+
+    #[test]
+    fn overflowing_score() {
+        // Given
+        let location_lists = Day01::parse("1500000000   1500000000\n1   1500000000");
+
+        // When
+        let result = location_lists.get_similarity_score::<i32>();
+
+        // Then
+        assert_eq!(
+            result,
+            Err(Overflow {
+                index: 0,
+                item: String::from("1500000000 seen 2 times")
+            })
+        );
+    }
+}
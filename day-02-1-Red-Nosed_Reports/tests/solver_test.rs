@@ -1,22 +0,0 @@
-mod process_lines {
-    use aoc2024::solver::process_lines;
-
-    #[test]
-    fn puzzle_subject_example() {
-        // When
-        let input = &[
-            "7 6 4 2 1",
-            "1 2 7 8 9",
-            "9 7 6 2 1",
-            "1 3 2 4 5",
-            "8 6 4 4 1",
-            "1 3 6 7 9",
-        ];
-
-        // When
-        let result = process_lines(input.iter().map(|x| x.to_string()));
-
-        // Then
-        assert_eq!(result, "2");
-    }
-}
@@ -1,3 +1,7 @@
+use aoc2024_common::day::Day;
+use aoc2024_common::exec::Executor;
+use tracing::instrument;
+
 const MAX_DIFF: i32 = 3;
 const MIN_DIFF: i32 = 1;
 
@@ -32,6 +36,7 @@ impl GreaterLower {
     }
 }
 
+#[derive(Debug)]
 pub struct Report {
     list: Vec<i32>,
 }
@@ -41,16 +46,39 @@ impl Report {
         Report { list }
     }
 
+    pub fn get_levels(&self) -> &[i32] {
+        &self.list
+    }
+
     pub fn is_safe(&self) -> bool {
         let comparator = self.get_comparator();
         match comparator {
-            Some(comparator) => self.test_adjacent_naive(|left, right| {
+            Some(comparator) => self.test_adjacent(|left, right| {
                 comparator(left, right) && is_adjacent_diff_acceptable(left, right)
             }),
             None => false,
         }
     }
 
+    /// Same as `is_safe`, but tolerates a single bad level
+    pub fn is_safe_with_dampener(&self) -> bool {
+        self.is_safe_with_dampener_cancellable(&Executor::sequential())
+    }
+
+    /// Same as `is_safe_with_dampener`, checking the cancel token of the
+    /// executor before each level removal
+    #[instrument(level = "trace", skip_all)]
+    pub fn is_safe_with_dampener_cancellable(&self, executor: &Executor) -> bool {
+        let comparator = self.get_comparator();
+        match comparator {
+            Some(comparator) => self.test_adjacent_naive(
+                |left, right| comparator(left, right) && is_adjacent_diff_acceptable(left, right),
+                executor,
+            ),
+            None => false,
+        }
+    }
+
     fn get_comparator(&self) -> Option<fn(i32, i32) -> bool> {
         let greater_lower = self.get_nb_greater_lower();
         match greater_lower.nb_greater.cmp(&greater_lower.nb_lower) {
@@ -73,14 +101,23 @@ impl Report {
             })
     }
 
+    fn test_adjacent<F: Fn(i32, i32) -> bool>(&self, callback: F) -> bool {
+        is_list_safe(&self.list, &callback)
+    }
+
     // Naive implementation, but only take 8ms..
-    fn test_adjacent_naive<F: Fn(i32, i32) -> bool>(&self, is_adjacent_acceptable: F) -> bool {
+    fn test_adjacent_naive<F: Fn(i32, i32) -> bool>(
+        &self,
+        is_adjacent_acceptable: F,
+        executor: &Executor,
+    ) -> bool {
         if is_list_safe(&self.list, &is_adjacent_acceptable) {
             return true;
         }
 
         let nb_elements = self.list.len();
         (0..nb_elements).any(|index| {
+            executor.check_cancelled();
             let mut list = self.list.clone();
             list.remove(index);
             is_list_safe(&list, &is_adjacent_acceptable)
@@ -135,11 +172,24 @@ impl From<&str> for Report {
     }
 }
 
-pub fn process_lines<T>(lines: T) -> String
-where
-    T: Iterator<Item = String>,
-{
-    let reports = lines.map(|line| Report::from(line.as_str()));
-    let nb_safe_reports = reports.filter(Report::is_safe).count();
-    nb_safe_reports.to_string()
+pub struct Day02;
+
+impl Day for Day02 {
+    type Parsed<'a> = Vec<Report>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.lines().map(Report::from).collect()
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>, executor: &Executor) -> String {
+        let nb_safe_reports = executor.count(parsed, Report::is_safe);
+        nb_safe_reports.to_string()
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>, executor: &Executor) -> String {
+        let nb_safe_reports = executor.count(parsed, |report| {
+            report.is_safe_with_dampener_cancellable(executor)
+        });
+        nb_safe_reports.to_string()
+    }
 }
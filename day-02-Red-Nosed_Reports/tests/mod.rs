@@ -1,4 +1,5 @@
 #![warn(clippy::pedantic)]
 
 pub mod helpers_test;
+pub mod snapshot_test;
 pub mod solver_test;
@@ -0,0 +1,56 @@
+const PUZZLE_SUBJECT_EXAMPLE: &[&str] = &[
+    "7 6 4 2 1",
+    "1 2 7 8 9",
+    "9 7 6 2 1",
+    "1 3 2 4 5",
+    "8 6 4 4 1",
+    "1 3 6 7 9",
+];
+
+mod parse {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::assert_named_snapshot;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_02::solver::Day02;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+
+        // When
+        let result = Day02::parse(&input);
+
+        // Then
+        assert_named_snapshot!("parse", result);
+    }
+}
+
+mod is_safe {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::assert_named_snapshot;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_02::solver::Day02;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let reports = Day02::parse(&input);
+
+        // When
+        let result: Vec<(&[i32], bool, bool)> = reports
+            .iter()
+            .map(|report| {
+                (
+                    report.get_levels(),
+                    report.is_safe(),
+                    report.is_safe_with_dampener(),
+                )
+            })
+            .collect();
+
+        // Then
+        assert_named_snapshot!("safety", result);
+    }
+}
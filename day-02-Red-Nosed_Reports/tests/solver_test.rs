@@ -1,5 +1,8 @@
-mod process_lines {
-    use aoc2024::solver::process_lines;
+#![allow(clippy::bool_assert_comparison)]
+
+mod part1 {
+    use aoc2024_common::day::solve_part1;
+    use aoc2024_day_02::solver::Day02;
 
     #[test]
     fn puzzle_subject_example() {
@@ -14,7 +17,31 @@ mod process_lines {
         ];
 
         // When
-        let result = process_lines(input.iter().map(|x| x.to_string()));
+        let result = solve_part1::<Day02>(&input.join("\n"));
+
+        // Then
+        assert_eq!(result, "2");
+    }
+}
+
+mod part2 {
+    use aoc2024_common::day::solve_part2;
+    use aoc2024_day_02::solver::Day02;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // When
+        let input = &[
+            "7 6 4 2 1",
+            "1 2 7 8 9",
+            "9 7 6 2 1",
+            "1 3 2 4 5",
+            "8 6 4 4 1",
+            "1 3 6 7 9",
+        ];
+
+        // When
+        let result = solve_part2::<Day02>(&input.join("\n"));
 
         // Then
         assert_eq!(result, "4");
@@ -23,16 +50,44 @@ mod process_lines {
 
 mod report {
     mod is_safe {
-        use aoc2024::solver::Report;
+        use aoc2024_day_02::solver::Report;
 
         #[test]
-        fn puzzle_subject_example_1_3_2_4_5_safe() {
+        fn puzzle_subject_example_7_6_4_2_1_safe() {
+            // Given
+            let report = Report::from("7 6 4 2 1");
+
+            // When
+            let result = report.is_safe();
+
+            // Then
+            assert_eq!(result, true);
+        }
+
+        #[test]
+        fn puzzle_subject_example_1_3_2_4_5_unsafe() {
             // Given
             let report = Report::from("1 3 2 4 5");
 
             // When
             let result = report.is_safe();
 
+            // Then
+            assert_eq!(result, false);
+        }
+    }
+
+    mod is_safe_with_dampener {
+        use aoc2024_day_02::solver::Report;
+
+        #[test]
+        fn puzzle_subject_example_1_3_2_4_5_safe() {
+            // Given
+            let report = Report::from("1 3 2 4 5");
+
+            // When
+            let result = report.is_safe_with_dampener();
+
             // Then
             assert_eq!(result, true);
         }
@@ -43,7 +98,7 @@ mod report {
             let report = Report::from("8 6 4 4 1");
 
             // When
-            let result = report.is_safe();
+            let result = report.is_safe_with_dampener();
 
             // Then
             assert_eq!(result, true);
@@ -55,7 +110,7 @@ mod report {
             let report = Report::from("12 10 13 16 19 21 22");
 
             // When
-            let result = report.is_safe();
+            let result = report.is_safe_with_dampener();
 
             // Then
             assert_eq!(result, true);
@@ -67,7 +122,7 @@ mod report {
             let report = Report::from("9 2 3 4 5");
 
             // When
-            let result = report.is_safe();
+            let result = report.is_safe_with_dampener();
 
             // Then
             assert_eq!(result, true);
@@ -79,7 +134,7 @@ mod report {
             let report = Report::from("1 2 3 4 9");
 
             // When
-            let result = report.is_safe();
+            let result = report.is_safe_with_dampener();
 
             // Then
             assert_eq!(result, true);
@@ -91,7 +146,7 @@ mod report {
             let report = Report::from("57 56 57 59 60 63 64 65");
 
             // When
-            let result = report.is_safe();
+            let result = report.is_safe_with_dampener();
 
             // Then
             assert_eq!(result, true);
@@ -103,7 +158,7 @@ mod report {
             let report = Report::from("91 92 95 93 94");
 
             // When
-            let result = report.is_safe();
+            let result = report.is_safe_with_dampener();
 
             // Then
             assert_eq!(result, true);
@@ -115,7 +170,7 @@ mod report {
             let report = Report::from("16 13 15 13 12 11 9 6");
 
             // When
-            let result = report.is_safe();
+            let result = report.is_safe_with_dampener();
 
             // Then
             assert_eq!(result, true);
@@ -127,7 +182,7 @@ mod report {
             let report = Report::from("40 41 43 44 47 46 47 49");
 
             // When
-            let result = report.is_safe();
+            let result = report.is_safe_with_dampener();
 
             // Then
             assert_eq!(result, true);
@@ -139,7 +194,7 @@ mod report {
             let report = Report::from("53 55 56 59 62 61 65");
 
             // When
-            let result = report.is_safe();
+            let result = report.is_safe_with_dampener();
 
             // Then
             assert_eq!(result, true);
@@ -151,7 +206,7 @@ mod report {
             let report = Report::from("86 85 84 81 80 81 77");
 
             // When
-            let result = report.is_safe();
+            let result = report.is_safe_with_dampener();
 
             // Then
             assert_eq!(result, true);
@@ -0,0 +1,192 @@
+use std::fmt;
+
+use crate::solver::{Parser, Payload, MAX_DIGIT, MUL_HEADER};
+
+const OPENING_BRACKETS: [u8; 4] = *b"([{<";
+const CLOSING_BRACKETS: [u8; 4] = *b")]}>";
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Reason {
+    /// Spaces or tabs anywhere after `mul`
+    Whitespace,
+    OpeningBracket(char),
+    ClosingBracket(char),
+    TooManyDigits(String),
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::Whitespace => write!(f, "whitespace inside the instruction"),
+            Reason::OpeningBracket(bracket) => write!(f, "opens with `{bracket}` instead of `(`"),
+            Reason::ClosingBracket(bracket) => write!(f, "closes with `{bracket}` instead of `)`"),
+            Reason::TooManyDigits(number) => {
+                write!(f, "`{number}` has more than {MAX_DIGIT} digits")
+            }
+        }
+    }
+}
+
+/// Text shaped like a `mul`, rejected by the parser
+#[derive(PartialEq, Eq, Debug)]
+pub struct NearMiss {
+    /// Byte offset in the whole input
+    pub offset: usize,
+    pub text: String,
+    /// In the order they appear in the text
+    pub reasons: Vec<Reason>,
+}
+
+/// Valid `mul` ignored in part 2
+#[derive(PartialEq, Eq, Debug)]
+pub struct SuppressedMul {
+    pub offset: usize,
+    pub left: i32,
+    pub right: i32,
+    /// Offset of the `don't()` disabling it
+    pub dont_offset: usize,
+}
+
+pub struct Lint {
+    pub near_misses: Vec<NearMiss>,
+    pub suppressed_muls: Vec<SuppressedMul>,
+}
+
+/// Lists what the parser rejects or part 2 ignores, to check them against the
+/// puzzle statement.
+///
+/// A near miss is a `mul` that would be valid if brackets of any kind, numbers
+/// of any length and whitespace around each part were accepted. Other text is
+/// only noise and is not reported.
+pub fn lint(input: &str) -> Lint {
+    let mut near_misses = Vec::new();
+    let mut suppressed_muls = Vec::new();
+    // Offset of the last `don't()` while disabled, across lines as in part 2
+    let mut disabled_by = None;
+
+    let mut line_offset = 0;
+    for line in input.split_inclusive('\n') {
+        near_misses.extend(
+            line.match_indices(MUL_HEADER)
+                .filter_map(|(index, _)| check_near_miss(line, index))
+                .map(|near_miss| NearMiss {
+                    offset: line_offset + near_miss.offset,
+                    ..near_miss
+                }),
+        );
+
+        let mut parser = Parser::new();
+        parser.parse_line(line);
+        for instruction in parser.program {
+            let offset = line_offset + instruction.index;
+            match instruction.payload {
+                Payload::Do => disabled_by = None,
+                Payload::DoNot => disabled_by = disabled_by.or(Some(offset)),
+                Payload::Mul(left, right) => {
+                    if let Some(dont_offset) = disabled_by {
+                        suppressed_muls.push(SuppressedMul {
+                            offset,
+                            left,
+                            right,
+                            dont_offset,
+                        });
+                    }
+                }
+            }
+        }
+        line_offset += line.len();
+    }
+
+    Lint {
+        near_misses,
+        suppressed_muls,
+    }
+}
+
+/// `None` if the `mul` at `index` is valid, or is not shaped like one. The
+/// offset of the returned near miss is in the line.
+fn check_near_miss(line: &str, index: usize) -> Option<NearMiss> {
+    let mut cursor = Cursor {
+        bytes: line.as_bytes(),
+        position: index + MUL_HEADER.len(),
+        reasons: Vec::new(),
+    };
+    cursor.skip_whitespace();
+    cursor.read_bracket(OPENING_BRACKETS, Reason::OpeningBracket)?;
+    cursor.skip_whitespace();
+    cursor.read_number()?;
+    cursor.skip_whitespace();
+    cursor.read_comma()?;
+    cursor.skip_whitespace();
+    cursor.read_number()?;
+    cursor.skip_whitespace();
+    cursor.read_bracket(CLOSING_BRACKETS, Reason::ClosingBracket)?;
+
+    if cursor.reasons.is_empty() {
+        return None;
+    }
+    Some(NearMiss {
+        offset: index,
+        text: line[index..cursor.position].to_string(),
+        reasons: cursor.reasons,
+    })
+}
+
+/// Reads a lenient `mul`, noting each deviation from the strict syntax
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    reasons: Vec<Reason>,
+}
+
+impl Cursor<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let start = self.position;
+        while matches!(self.peek(), Some(b' ' | b'\t')) {
+            self.position += 1;
+        }
+        if self.position > start && !self.reasons.contains(&Reason::Whitespace) {
+            self.reasons.push(Reason::Whitespace);
+        }
+    }
+
+    /// The first of `brackets` is the expected one
+    fn read_bracket(&mut self, brackets: [u8; 4], reason: fn(char) -> Reason) -> Option<()> {
+        let bracket = self.peek()?;
+        let bracket_index = brackets.iter().position(|&b| b == bracket)?;
+        if bracket_index != 0 {
+            self.reasons.push(reason(char::from(bracket)));
+        }
+        self.position += 1;
+        Some(())
+    }
+
+    fn read_number(&mut self) -> Option<()> {
+        let start = self.position;
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.position += 1;
+        }
+        let length = self.position - start;
+        if length == 0 {
+            return None;
+        }
+        if length > MAX_DIGIT {
+            let number = String::from_utf8_lossy(&self.bytes[start..self.position]);
+            self.reasons
+                .push(Reason::TooManyDigits(number.into_owned()));
+        }
+        Some(())
+    }
+
+    fn read_comma(&mut self) -> Option<()> {
+        if self.peek()? != b',' {
+            return None;
+        }
+        self.position += 1;
+        Some(())
+    }
+}
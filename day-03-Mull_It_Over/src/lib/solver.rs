@@ -0,0 +1,224 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::mem;
+use std::{cell::Cell, collections::BTreeSet};
+
+use aoc2024_common::accumulator::{Accumulator, Overflow, Wide};
+use aoc2024_common::day::Day;
+use aoc2024_common::exec::Executor;
+use tracing::instrument;
+
+pub(crate) const MAX_DIGIT: usize = 3;
+pub(crate) const MUL_HEADER: &str = "mul";
+const MUL_HEADER_LEN: usize = MUL_HEADER.len();
+const DO_HEADER: &str = "do()";
+const DO_NO_HEADER: &str = "don't()";
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Instruction {
+    pub(crate) index: usize,
+    pub(crate) payload: Payload,
+}
+
+impl Ord for Instruction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index.cmp(&other.index)
+    }
+}
+
+#[allow(clippy::non_canonical_partial_ord_impl)]
+impl PartialOrd for Instruction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.index.cmp(&other.index))
+    }
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub enum Payload {
+    Do,
+    DoNot,
+    Mul(i32, i32),
+}
+
+impl Display for Payload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Payload::Do => write!(f, "{DO_HEADER}"),
+            Payload::DoNot => write!(f, "{DO_NO_HEADER}"),
+            Payload::Mul(left, right) => write!(f, "{MUL_HEADER}({left},{right})"),
+        }
+    }
+}
+
+fn try_parse_mul(mut input: &str, index: usize) -> Option<Instruction> {
+    let parenthesis_left_idx = input.find('(')?;
+    if parenthesis_left_idx != MUL_HEADER_LEN {
+        return None;
+    }
+    input = &input[(MUL_HEADER_LEN + 1)..];
+
+    let comma_idx = input.find(',')?;
+    let left_number = &input[..comma_idx];
+    if left_number.is_empty()
+        || left_number.len() > MAX_DIGIT
+        || left_number.chars().any(|c| !c.is_ascii_digit())
+    {
+        return None;
+    }
+    let left = left_number.parse().unwrap();
+
+    input = &input[comma_idx + 1..];
+
+    let right_parenthesis_idx = input.find(')')?;
+    let right_number = &input[..right_parenthesis_idx];
+    if right_number.is_empty()
+        || right_number.len() > MAX_DIGIT
+        || right_number.chars().any(|c| !c.is_ascii_digit())
+    {
+        return None;
+    }
+    let right = right_number.parse().unwrap();
+
+    Some(Instruction {
+        index,
+        payload: Payload::Mul(left, right),
+    })
+}
+
+pub(crate) struct Parser {
+    instructions: Cell<BTreeSet<Instruction>>,
+    pub(crate) program: Vec<Instruction>,
+}
+
+impl Parser {
+    pub(crate) fn new() -> Self {
+        Self {
+            instructions: Cell::new(BTreeSet::new()),
+            program: Vec::new(),
+        }
+    }
+
+    pub(crate) fn parse_line(&mut self, line: &str) {
+        self.parse_mul_instructions(line);
+        self.parse_do_instructions(line);
+        self.parse_dont_instructions(line);
+        let instructions = self.instructions.replace(BTreeSet::new());
+        self.program.extend(instructions);
+    }
+
+    fn parse_mul_instructions(&mut self, input: &str) {
+        input
+            .match_indices(MUL_HEADER)
+            .filter_map(|(idx, _)| {
+                let instruction_input = &input[idx..];
+                try_parse_mul(instruction_input, idx)
+            })
+            .for_each(|instruction| {
+                self.instructions.get_mut().insert(instruction);
+            });
+    }
+
+    fn parse_do_instructions(&mut self, input: &str) {
+        input.match_indices(DO_HEADER).for_each(|(idx, _)| {
+            self.instructions.get_mut().insert(Instruction {
+                index: idx,
+                payload: Payload::Do,
+            });
+        });
+    }
+
+    fn parse_dont_instructions(&mut self, input: &str) {
+        input.match_indices(DO_NO_HEADER).for_each(|(idx, _)| {
+            self.instructions.get_mut().insert(Instruction {
+                index: idx,
+                payload: Payload::DoNot,
+            });
+        });
+    }
+}
+
+struct Processor<A> {
+    is_enabled: bool,
+    is_conditional: bool,
+    result: A,
+}
+
+impl<A: Accumulator> Processor<A> {
+    /// A non conditional processor ignores `do()` and `don't()`
+    fn new(is_conditional: bool) -> Self {
+        Self {
+            is_enabled: true,
+            is_conditional,
+            result: A::zero(),
+        }
+    }
+
+    /// Returns `None` on overflow
+    fn process_instruction(&mut self, instruction: &Instruction) -> Option<()> {
+        match instruction.payload {
+            Payload::Do => self.is_enabled = true,
+            Payload::DoNot => self.is_enabled = !self.is_conditional,
+            Payload::Mul(left, right) => {
+                if self.is_enabled {
+                    let product =
+                        A::from_i64(left.into())?.checked_mul(A::from_i64(right.into())?)?;
+                    let result = mem::replace(&mut self.result, A::zero());
+                    self.result = result.checked_add(product)?;
+                }
+            }
+        }
+        Some(())
+    }
+
+    fn run(mut self, program: &[Instruction]) -> Result<A, Overflow> {
+        for (index, instruction) in program.iter().enumerate() {
+            if self.process_instruction(instruction).is_none() {
+                return Err(Overflow {
+                    index,
+                    item: instruction.payload.to_string(),
+                });
+            }
+        }
+        Ok(self.result)
+    }
+}
+
+/// Sum of the enabled products, `do()` and `don't()` being ignored if the
+/// program is not conditional
+///
+/// # Errors
+///
+/// Will return an error, with the instruction and its index in the program, if:
+/// - The sum or a product overflows `A`
+#[instrument(level = "debug", skip_all)]
+pub fn sum_products<A: Accumulator>(
+    program: &[Instruction],
+    is_conditional: bool,
+) -> Result<A, Overflow> {
+    Processor::new(is_conditional).run(program)
+}
+
+pub struct Day03;
+
+impl Day for Day03 {
+    /// Instructions of every line, each line sorted by position
+    type Parsed<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        let mut parser = Parser::new();
+        input.lines().for_each(|line| parser.parse_line(line));
+        parser.program
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>, _executor: &Executor) -> String {
+        sum_products::<Wide>(parsed, false)
+            .unwrap_or_else(|overflow| panic!("{overflow}"))
+            .to_string()
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>, _executor: &Executor) -> String {
+        sum_products::<Wide>(parsed, true)
+            .unwrap_or_else(|overflow| panic!("{overflow}"))
+            .to_string()
+    }
+}
@@ -0,0 +1,8 @@
+#![warn(clippy::pedantic)]
+
+use aoc2024_common::day::run_from_args_file;
+use aoc2024_day_03::solver::Day03;
+
+fn main() {
+    run_from_args_file::<Day03>();
+}
@@ -0,0 +1,101 @@
+mod lint {
+    use aoc2024_day_03::lint::{lint, NearMiss, Reason, SuppressedMul};
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+        // When
+        let result = lint(input);
+
+        // Then
+        assert_eq!(
+            result.near_misses,
+            vec![
+                NearMiss {
+                    offset: 10,
+                    text: String::from("mul[3,7]"),
+                    reasons: vec![Reason::OpeningBracket('['), Reason::ClosingBracket(']')],
+                },
+                NearMiss {
+                    offset: 37,
+                    text: String::from("mul(32,64]"),
+                    reasons: vec![Reason::ClosingBracket(']')],
+                },
+            ]
+        );
+        assert_eq!(
+            result.suppressed_muls,
+            vec![
+                SuppressedMul {
+                    offset: 28,
+                    left: 5,
+                    right: 5,
+                    dont_offset: 20,
+                },
+                SuppressedMul {
+                    offset: 48,
+                    left: 11,
+                    right: 8,
+                    dont_offset: 20,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn whitespace_and_long_numbers() {
+        // Given
+        let input = "mul(1,2)\nmul ( 2 , 4 )?mul(1234,5)";
+
+        // When
+        let result = lint(input);
+
+        // Then
+        assert_eq!(
+            result.near_misses,
+            vec![
+                NearMiss {
+                    offset: 9,
+                    text: String::from("mul ( 2 , 4 )"),
+                    reasons: vec![Reason::Whitespace],
+                },
+                NearMiss {
+                    offset: 23,
+                    text: String::from("mul(1234,5)"),
+                    reasons: vec![Reason::TooManyDigits(String::from("1234"))],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn noise_is_not_reported() {
+        // Given
+        let input = "mul(a,2)mul(2;3)mul(4,5mul mul()";
+
+        // When
+        let result = lint(input);
+
+        // Then
+        assert!(result.near_misses.is_empty());
+    }
+
+    #[test]
+    fn disabled_across_lines() {
+        // Given
+        let input = "don't()mul(1,2)\nmul(3,4)do()mul(5,6)";
+
+        // When
+        let result = lint(input);
+
+        // Then
+        let offsets: Vec<usize> = result
+            .suppressed_muls
+            .iter()
+            .map(|suppressed_mul| suppressed_mul.offset)
+            .collect();
+        assert_eq!(offsets, vec![7, 16]);
+    }
+}
@@ -1,4 +1,6 @@
 #![warn(clippy::pedantic)]
 
 pub mod helpers_test;
+pub mod lint_test;
+pub mod snapshot_test;
 pub mod solver_test;
@@ -0,0 +1,29 @@
+mod parse {
+    use aoc2024_common::assert_named_snapshot;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_03::solver::Day03;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+        // When
+        let result = Day03::parse(input);
+
+        // Then
+        assert_named_snapshot!("parse", result);
+    }
+
+    #[test]
+    fn several_lines() {
+        // Given
+        let input = "mul(1,2)don't()\nmul(3,4)do()mul(5,6)";
+
+        // When
+        let result = Day03::parse(input);
+
+        // Then
+        assert_named_snapshot!("parse_several_lines", result);
+    }
+}
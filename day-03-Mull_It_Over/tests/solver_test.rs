@@ -0,0 +1,71 @@
+mod part1 {
+    use aoc2024_common::day::solve_part1;
+    use aoc2024_day_03::solver::Day03;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // When
+        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+
+        // When
+        let result = solve_part1::<Day03>(input);
+
+        // Then
+        assert_eq!(result, "161");
+    }
+}
+
+mod part2 {
+    use aoc2024_common::day::solve_part2;
+    use aoc2024_day_03::solver::Day03;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // When
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+        // When
+        let result = solve_part2::<Day03>(input);
+
+        // Then
+        assert_eq!(result, "48");
+    }
+}
+
+mod sum_products {
+    use aoc2024_common::accumulator::Overflow;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_03::solver::{sum_products, Day03};
+
+    #[test]
+    fn overflowing_instruction() {
+        // Given
+        let input = "do()mul(999,999)".repeat(2200);
+        let program = Day03::parse(&input);
+
+        // When
+        let result = sum_products::<i32>(&program, true);
+
+        // Then
+        assert_eq!(
+            result,
+            Err(Overflow {
+                index: 2 * 2151 + 1,
+                item: String::from("mul(999,999)")
+            })
+        );
+    }
+
+    #[test]
+    fn wider_accumulator() {
+        // Given
+        let input = "do()mul(999,999)".repeat(2200);
+        let program = Day03::parse(&input);
+
+        // When
+        let result = sum_products::<i64>(&program, true);
+
+        // Then
+        assert_eq!(result, Ok(998_001 * 2200));
+    }
+}
@@ -1,4 +0,0 @@
-#![warn(clippy::pedantic)]
-
-pub mod helpers_test;
-pub mod solver_test;
@@ -1,6 +1,6 @@
 #![warn(clippy::pedantic)]
 #![allow(clippy::must_use_candidate)]
 
-pub mod common;
 pub mod helpers;
+pub mod scanner;
 pub mod solver;
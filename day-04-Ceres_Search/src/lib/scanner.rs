@@ -0,0 +1,63 @@
+use std::collections::BTreeMap;
+
+use aho_corasick::AhoCorasick;
+
+/// Counts words in lines, in both reading directions, with a single pass of an
+/// Aho-Corasick automaton per line.
+///
+/// Each word is searched as written and reversed, so a palindrome is counted
+/// twice, as when it is read from both of its ends.
+pub struct WordScanner {
+    automaton: AhoCorasick,
+    /// Number of searched words, written or reversed, behind each pattern
+    weights: Vec<usize>,
+    max_length: usize,
+}
+
+impl WordScanner {
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - A word is empty
+    pub fn new(words: &[&[u8]]) -> Self {
+        let mut patterns: BTreeMap<Vec<u8>, usize> = BTreeMap::new();
+        for &word in words {
+            assert!(!word.is_empty(), "Cannot search an empty word");
+            *patterns.entry(word.to_vec()).or_default() += 1;
+            *patterns
+                .entry(word.iter().rev().copied().collect())
+                .or_default() += 1;
+        }
+
+        let automaton = AhoCorasick::new(patterns.keys()).expect("Cannot build the automaton");
+        let max_length = words.iter().map(|word| word.len()).max().unwrap_or(0);
+        Self {
+            automaton,
+            weights: patterns.into_values().collect(),
+            max_length,
+        }
+    }
+
+    pub fn count_in_line(&self, line: &[u8]) -> usize {
+        self.automaton
+            .find_overlapping_iter(line)
+            .map(|found| self.weights[found.pattern().as_usize()])
+            .sum()
+    }
+
+    /// Words starting anywhere in the cycle, going on from its beginning once
+    /// its end is reached, as many times as needed
+    pub fn count_in_cycle(&self, cycle: &[u8]) -> usize {
+        let extended: Vec<u8> = cycle
+            .iter()
+            .cycle()
+            .take(cycle.len() + self.max_length.saturating_sub(1))
+            .copied()
+            .collect();
+        self.automaton
+            .find_overlapping_iter(&extended)
+            .filter(|found| found.start() < cycle.len())
+            .map(|found| self.weights[found.pattern().as_usize()])
+            .sum()
+    }
+}
@@ -0,0 +1,383 @@
+use aoc2024_common::day::Day;
+use aoc2024_common::exec::Executor;
+use aoc2024_common::grid::ByteGrid;
+use tracing::instrument;
+
+use crate::scanner::WordScanner;
+
+const SEARCHED_WORD: [u8; 4] = *b"XMAS";
+
+const CROSS_CENTER_CHAR: u8 = b'A';
+const CROSS_CHARS_SEQUENCE: [u8; 4] = *b"MMSS";
+
+/// x, y and z, the layer, which is always 0 for a flat grid
+type Coordinate = [i32; 3];
+
+const CROSS_POSITIONS_SEQUENCE: [Coordinate; 4] = [[-1, 1, 0], [1, 1, 0], [1, -1, 0], [-1, -1, 0]];
+
+fn add_coordinates(a: Coordinate, b: Coordinate) -> Coordinate {
+    let [x1, y1, z1] = a;
+    let [x2, y2, z2] = b;
+    [x1 + x2, y1 + y2, z1 + z2]
+}
+
+/// The two axes other than `axis`, in increasing order
+fn get_other_axes(axis: usize) -> [usize; 2] {
+    match axis {
+        0 => [1, 2],
+        1 => [0, 2],
+        _ => [0, 1],
+    }
+}
+
+/// What lies past the edges of the grid
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub enum Edges {
+    /// Nothing, words stop at the edges
+    #[default]
+    Bounded,
+    /// The opposite edge, words continue across it as on a torus
+    Wrapping,
+}
+
+/// Layers of letters, one for a flat grid, stacked along z
+#[derive(Debug)]
+pub struct Grid<'a> {
+    layers: Vec<ByteGrid<'a>>,
+    /// Words are also searched across the layers
+    is_layered: bool,
+    edges: Edges,
+}
+
+/// Every cell from `start` along `vector`, up to the edge of the grid, or
+/// until the line comes back to `start` when the edges wrap
+#[derive(Copy, Clone)]
+struct Line {
+    start: Coordinate,
+    vector: Coordinate,
+}
+
+impl<'a> Grid<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            layers: vec![ByteGrid::new(input)],
+            is_layered: false,
+            edges: Edges::Bounded,
+        }
+    }
+
+    /// 3D grid whose layers, from z = 0, are separated by blank lines. Words
+    /// are searched in all 26 directions.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - The layers do not all have the same size
+    pub fn new_layered(input: &'a str) -> Self {
+        let layers: Vec<ByteGrid<'a>> =
+            split_layers(input).into_iter().map(ByteGrid::new).collect();
+        if let Some(first) = layers.first() {
+            let (width, height) = (first.get_width(), first.get_height());
+            for (z, layer) in layers.iter().enumerate() {
+                assert!(
+                    layer.get_width() == width && layer.get_height() == height,
+                    "Layer {z} is {}x{} instead of {width}x{height}",
+                    layer.get_width(),
+                    layer.get_height()
+                );
+            }
+        }
+
+        Self {
+            layers,
+            is_layered: true,
+            edges: Edges::Bounded,
+        }
+    }
+
+    #[must_use]
+    pub fn with_edges(self, edges: Edges) -> Self {
+        Self { edges, ..self }
+    }
+}
+
+impl Grid<'_> {
+    /// Occurrences of the words in all directions, each word being counted as
+    /// many times as it is given. With wrapping edges, a word longer than its
+    /// line goes round it more than once.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - A word is empty
+    #[instrument(level = "debug", skip_all)]
+    pub fn count_words(&self, words: &[&[u8]], executor: &Executor) -> usize {
+        let scanner = WordScanner::new(words);
+        executor.sum(&self.get_lines(), |&line| match self.edges {
+            Edges::Bounded if line.vector == [1, 0, 0] => {
+                let [_, y, z] = line.start;
+                let y = usize::try_from(y).unwrap();
+                let z = usize::try_from(z).unwrap();
+                scanner.count_in_line(self.layers[z].row(y))
+            }
+            Edges::Bounded => scanner.count_in_line(&self.read_line(line, executor)),
+            Edges::Wrapping => scanner.count_in_cycle(&self.read_line(line, executor)),
+        })
+    }
+
+    /// Half of the directions, lines are read both ways: the vectors whose
+    /// first non-zero component is positive
+    fn get_line_vectors(&self) -> Vec<Coordinate> {
+        let z_range = if self.is_layered { -1..=1 } else { 0..=0 };
+        let mut vectors = Vec::new();
+        for dz in z_range {
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let vector = [dx, dy, dz];
+                    if vector.iter().find(|&&component| component != 0) == Some(&1) {
+                        vectors.push(vector);
+                    }
+                }
+            }
+        }
+        vectors
+    }
+
+    /// Every line of every direction, each read in one direction only. Only
+    /// the faces of the grid are walked, the lines entering through them.
+    fn get_lines(&self) -> Vec<Line> {
+        let mut lines = Vec::new();
+        for vector in self.get_line_vectors() {
+            let starts = match self.edges {
+                Edges::Bounded => self.get_line_starts(vector),
+                Edges::Wrapping => self.get_cycle_starts(vector),
+            };
+            lines.extend(starts.into_iter().map(|start| Line { start, vector }));
+        }
+        lines
+    }
+
+    /// Cells whose previous one along `vector` is outside the grid: those of
+    /// the faces the vector enters through
+    fn get_line_starts(&self, vector: Coordinate) -> Vec<Coordinate> {
+        let size = self.get_size();
+        // Component of the entry face along each axis the vector moves along
+        let entries: [Option<i32>; 3] = std::array::from_fn(|axis| match vector[axis] {
+            0 => None,
+            1.. => Some(0),
+            _ => Some(size[axis] - 1),
+        });
+        let mut starts = Vec::new();
+        for (axis, entry) in entries.iter().enumerate() {
+            let Some(entry) = *entry else {
+                continue;
+            };
+            // Cells on the face of an earlier axis are already there
+            starts.extend(
+                self.get_face(axis, entry).filter(|position| {
+                    (0..axis).all(|other| entries[other] != Some(position[other]))
+                }),
+            );
+        }
+        starts
+    }
+
+    /// One cell of each distinct cycle along `vector`. Each cycle crosses the
+    /// face of the first axis the vector moves along, coming back to it after
+    /// as many steps as the size of the grid along that axis.
+    fn get_cycle_starts(&self, vector: Coordinate) -> Vec<Coordinate> {
+        let size = self.get_size();
+        let axis = vector.iter().position(|&component| component != 0).unwrap();
+        let [first, second] = get_other_axes(axis);
+        let jump = vector.map(|component| component * size[axis]);
+        let face_index = |position: Coordinate| {
+            usize::try_from(position[first] * size[second] + position[second]).unwrap()
+        };
+
+        let face: Vec<Coordinate> = self.get_face(axis, 0).collect();
+        let mut is_on_cycle = vec![false; face.len()];
+        let mut starts = Vec::new();
+        for &start in &face {
+            if is_on_cycle[face_index(start)] {
+                continue;
+            }
+            let mut position = start;
+            loop {
+                is_on_cycle[face_index(position)] = true;
+                position = self.wrap(add_coordinates(position, jump));
+                if position == start {
+                    break;
+                }
+            }
+            starts.push(start);
+        }
+        starts
+    }
+
+    /// Cells whose component along `axis` is `value`, the other two
+    /// components in increasing order
+    fn get_face(&self, axis: usize, value: i32) -> impl Iterator<Item = Coordinate> {
+        let size = self.get_size();
+        let [first, second] = get_other_axes(axis);
+        (0..size[first]).flat_map(move |i| {
+            (0..size[second]).map(move |j| {
+                let mut position = [0; 3];
+                position[axis] = value;
+                position[first] = i;
+                position[second] = j;
+                position
+            })
+        })
+    }
+
+    fn read_line(&self, line: Line, executor: &Executor) -> Vec<u8> {
+        let mut letters = Vec::new();
+        let mut position = line.start;
+        while let Some(letter) = self.get_letter(position) {
+            executor.check_cancelled();
+            letters.push(letter);
+            position = add_coordinates(position, line.vector);
+            if self.edges == Edges::Wrapping && self.wrap(position) == line.start {
+                break;
+            }
+        }
+        letters
+    }
+
+    /// Centers of the X-MAS, in reading order, layer after layer
+    pub fn get_cross_centers(&self) -> Vec<Coordinate> {
+        self.get_positions()
+            .filter(|&position| self.test_cross(position))
+            .collect()
+    }
+
+    /// X-MAS are searched in each layer, with the edges of the layer wrapping
+    /// as those of the grid
+    #[instrument(level = "debug", skip_all)]
+    fn get_all_crosses(&self, executor: &Executor) -> usize {
+        executor.sum(&self.get_rows(), |&(y, z)| {
+            self.get_row_positions(y, z)
+                .filter(|&position| {
+                    executor.check_cancelled();
+                    self.test_cross(position)
+                })
+                .count()
+        })
+    }
+
+    fn get_rows(&self) -> Vec<(i32, i32)> {
+        let [_, height, depth] = self.get_size();
+        (0..depth)
+            .flat_map(|z| (0..height).map(move |y| (y, z)))
+            .collect()
+    }
+
+    fn get_row_positions(&self, y: i32, z: i32) -> impl Iterator<Item = Coordinate> {
+        let [width, _, _] = self.get_size();
+        (0..width).map(move |x| [x, y, z])
+    }
+
+    /// Every cell, in reading order, layer after layer
+    fn get_positions(&self) -> impl Iterator<Item = Coordinate> + '_ {
+        self.get_rows()
+            .into_iter()
+            .flat_map(|(y, z)| self.get_row_positions(y, z))
+    }
+
+    fn test_cross(&self, position: Coordinate) -> bool {
+        let Some(letter) = self.get_letter(position) else {
+            return false;
+        };
+        if letter != CROSS_CENTER_CHAR {
+            return false;
+        }
+
+        (0..CROSS_CHARS_SEQUENCE.len()).any(|index| self.test_combination(position, index))
+    }
+
+    fn test_combination(&self, origin: Coordinate, shift: usize) -> bool {
+        CROSS_POSITIONS_SEQUENCE
+            .iter()
+            .enumerate()
+            .all(|(index, vector)| {
+                let letter_index = (index + shift) % CROSS_CHARS_SEQUENCE.len();
+                let searched_letter = CROSS_CHARS_SEQUENCE[letter_index];
+                let position = add_coordinates(origin, *vector);
+                let Some(letter) = self.get_letter(position) else {
+                    return false;
+                };
+                letter == searched_letter
+            })
+    }
+
+    /// Returns `None` outside of a bounded grid
+    fn get_letter(&self, position: Coordinate) -> Option<u8> {
+        let [x, y, z] = match self.edges {
+            Edges::Bounded => position,
+            Edges::Wrapping => self.wrap(position),
+        };
+        let layer = self.layers.get(usize::try_from(z).ok()?)?;
+        layer.get(x, y)
+    }
+
+    /// Same cell inside the grid, as if the grid was repeated in every direction
+    fn wrap(&self, position: Coordinate) -> Coordinate {
+        let mut wrapped = position;
+        for (component, size) in wrapped.iter_mut().zip(self.get_size()) {
+            *component = component.rem_euclid(size);
+        }
+        wrapped
+    }
+
+    /// Width, height and depth
+    fn get_size(&self) -> Coordinate {
+        [self.get_width(), self.get_height(), self.layers.len()]
+            .map(|size| i32::try_from(size).unwrap())
+    }
+
+    fn get_width(&self) -> usize {
+        self.layers.first().map_or(0, ByteGrid::get_width)
+    }
+
+    fn get_height(&self) -> usize {
+        self.layers.first().map_or(0, ByteGrid::get_height)
+    }
+}
+
+/// Blocks of lines between blank lines, without copying them
+fn split_layers(input: &str) -> Vec<&str> {
+    let mut layers = Vec::new();
+    let mut start = 0;
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        if line.trim_end_matches(['\n', '\r']).is_empty() {
+            if offset > start {
+                layers.push(&input[start..offset]);
+            }
+            start = offset + line.len();
+        }
+        offset += line.len();
+    }
+    if offset > start {
+        layers.push(&input[start..]);
+    }
+    layers
+}
+
+pub struct Day04;
+
+impl Day for Day04 {
+    type Parsed<'a> = Grid<'a>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        Grid::new(input)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>, executor: &Executor) -> String {
+        parsed.count_words(&[&SEARCHED_WORD], executor).to_string()
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>, executor: &Executor) -> String {
+        parsed.get_all_crosses(executor).to_string()
+    }
+}
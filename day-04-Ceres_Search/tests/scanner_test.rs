@@ -0,0 +1,34 @@
+mod count_in_line {
+    use aoc2024_day_04::scanner::WordScanner;
+
+    #[test]
+    fn both_directions() {
+        // Given
+        let scanner = WordScanner::new(&[b"XMAS"]);
+
+        // When
+        let result = scanner.count_in_line(b"XMASAMX.XMAS");
+
+        // Then
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn overlapping_words() {
+        // Given
+        let scanner = WordScanner::new(&[b"ABAB", b"BA"]);
+
+        // When
+        let result = scanner.count_in_line(b"ABABA");
+
+        // Then
+        assert_eq!(result, 2 + 2 * 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot search an empty word")]
+    fn empty_word() {
+        // When
+        WordScanner::new(&[b""]);
+    }
+}
@@ -0,0 +1,51 @@
+const PUZZLE_SUBJECT_EXAMPLE: &[&str] = &[
+    "MMMSXXMASM",
+    "MSAMXMSMSA",
+    "AMXSXMAAMM",
+    "MSAMASMSMX",
+    "XMASAMXAMM",
+    "XXAMMXXAMA",
+    "SMSMSASXSS",
+    "SAXAMASAAA",
+    "MAMMMXMMMM",
+    "MXMXAXMASX",
+];
+
+mod parse {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::assert_named_snapshot;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_04::solver::Day04;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+
+        // When
+        let result = Day04::parse(&input);
+
+        // Then
+        assert_named_snapshot!("parse", result);
+    }
+}
+
+mod get_cross_centers {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::assert_named_snapshot;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_04::solver::Day04;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let grid = Day04::parse(&input);
+
+        // When
+        let result = grid.get_cross_centers();
+
+        // Then
+        assert_named_snapshot!("cross_centers", result);
+    }
+}
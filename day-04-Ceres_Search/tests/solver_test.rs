@@ -0,0 +1,225 @@
+const PUZZLE_SUBJECT_EXAMPLE: &[&str] = &[
+    "MMMSXXMASM",
+    "MSAMXMSMSA",
+    "AMXSXMAAMM",
+    "MSAMASMSMX",
+    "XMASAMXAMM",
+    "XXAMMXXAMA",
+    "SMSMSASXSS",
+    "SAXAMASAAA",
+    "MAMMMXMMMM",
+    "MXMXAXMASX",
+];
+
+mod part1 {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::day::solve_part1;
+    use aoc2024_day_04::solver::Day04;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // When
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+
+        // When
+        let result = solve_part1::<Day04>(&input);
+
+        // Then
+        assert_eq!(result, "18");
+    }
+}
+
+mod part2 {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::day::solve_part2;
+    use aoc2024_day_04::solver::Day04;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // When
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+
+        // When
+        let result = solve_part2::<Day04>(&input);
+
+        // Then
+        assert_eq!(result, "9");
+    }
+}
+
+mod part2_wrapping {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::day::Day;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_day_04::solver::{Day04, Edges, Grid};
+
+    #[test]
+    fn crosses_across_the_edges() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let grid = Grid::new(&input).with_edges(Edges::Wrapping);
+
+        // When
+        let result = Day04::part2(&grid, &Executor::sequential());
+
+        // Then
+        assert_eq!(result, "10");
+    }
+}
+
+mod count_words {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::day::Day;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_day_04::solver::Day04;
+
+    fn count_words(words: &[&[u8]]) -> usize {
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let grid = Day04::parse(&input);
+        grid.count_words(words, &Executor::new(4))
+    }
+
+    #[test]
+    fn several_words() {
+        // When
+        let result = count_words(&[b"XMAS", b"MAS"]);
+
+        // Then
+        assert_eq!(result, 18 + 38);
+    }
+
+    #[test]
+    fn palindrome() {
+        // When
+        let result = count_words(&[b"AXA"]);
+
+        // Then
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn single_letter_in_all_directions() {
+        // When
+        let result = count_words(&[b"X"]);
+
+        // Then
+        assert_eq!(result, 19 * 8);
+    }
+
+    #[test]
+    fn repeated_word() {
+        // When
+        let result = count_words(&[b"XMAS", b"XMAS"]);
+
+        // Then
+        assert_eq!(result, 36);
+    }
+}
+
+mod wrapping_edges {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_day_04::solver::{Edges, Grid};
+
+    #[test]
+    fn words_across_the_edges() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let grid = Grid::new(&input).with_edges(Edges::Wrapping);
+
+        // When
+        let result = grid.count_words(&[b"XMAS"], &Executor::sequential());
+
+        // Then
+        assert_eq!(result, 21);
+    }
+
+    #[test]
+    fn word_starting_at_the_end_of_a_row() {
+        // Given
+        let input = "MASX\n....";
+        let bounded_grid = Grid::new(input);
+        let wrapping_grid = Grid::new(input).with_edges(Edges::Wrapping);
+
+        // When
+        let bounded_result = bounded_grid.count_words(&[b"XMAS"], &Executor::sequential());
+        let wrapping_result = wrapping_grid.count_words(&[b"XMAS"], &Executor::sequential());
+
+        // Then
+        assert_eq!(bounded_result, 0);
+        assert_eq!(wrapping_result, 1);
+    }
+
+    #[test]
+    fn word_longer_than_a_row() {
+        // Given
+        let grid = Grid::new("XM\n..").with_edges(Edges::Wrapping);
+
+        // When
+        let result = grid.count_words(&[b"XMXM"], &Executor::sequential());
+
+        // Then
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn single_letter_on_a_rectangle() {
+        // Given
+        let grid = Grid::new("X.X.X.\n..X...\n.X....\nX....X").with_edges(Edges::Wrapping);
+
+        // When
+        let result = grid.count_words(&[b"X"], &Executor::sequential());
+
+        // Then
+        assert_eq!(result, 7 * 8);
+    }
+}
+
+mod layered {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_day_04::solver::{Edges, Grid};
+
+    #[test]
+    fn word_across_the_layers() {
+        // Given
+        let input = "X...\n....\n....\n....\n\n\
+                     ....\n.M..\n....\n....\n\n\
+                     ....\n....\n..A.\n....\n\n\
+                     ....\n....\n....\n...S\n";
+        let grid = Grid::new_layered(input);
+
+        // When
+        let result = grid.count_words(&[b"XMAS"], &Executor::sequential());
+
+        // Then
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn two_layers() {
+        // Given
+        let input = format!(
+            "{}\n\n{}",
+            PUZZLE_SUBJECT_EXAMPLE[..5].join("\n"),
+            PUZZLE_SUBJECT_EXAMPLE[5..].join("\n")
+        );
+        let bounded_grid = Grid::new_layered(&input);
+        let wrapping_grid = Grid::new_layered(&input).with_edges(Edges::Wrapping);
+
+        // When
+        let bounded_result = bounded_grid.count_words(&[b"XMAS"], &Executor::new(4));
+        let wrapping_result = wrapping_grid.count_words(&[b"XMAS"], &Executor::new(4));
+
+        // Then
+        assert_eq!(bounded_result, 14);
+        assert_eq!(wrapping_result, 38);
+    }
+
+    #[test]
+    #[should_panic(expected = "Layer 1 is 2x1 instead of 2x2")]
+    fn layers_of_different_sizes() {
+        // When
+        Grid::new_layered("XM\nAS\n\nXM\n");
+    }
+}
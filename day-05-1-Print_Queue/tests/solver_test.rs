@@ -1,44 +0,0 @@
-mod process_lines {
-    use aoc2024::solver::process_lines;
-
-    #[test]
-    fn puzzle_subject_example() {
-        // When
-        let input = &[
-            "47|53",
-            "97|13",
-            "97|61",
-            "97|47",
-            "75|29",
-            "61|13",
-            "75|53",
-            "29|13",
-            "97|29",
-            "53|29",
-            "61|53",
-            "97|53",
-            "61|29",
-            "47|13",
-            "75|47",
-            "97|75",
-            "47|61",
-            "75|61",
-            "47|29",
-            "75|13",
-            "53|13",
-            "",
-            "75,47,61,53,29",
-            "97,61,53,29,13",
-            "75,29,13",
-            "75,97,47,61,53",
-            "61,13,29",
-            "97,13,75,29,47",
-        ];
-
-        // When
-        let result = process_lines(input.iter().map(|x| x.to_string()));
-
-        // Then
-        assert_eq!(result, "143");
-    }
-}
@@ -0,0 +1,181 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use aoc2024_common::accumulator::{checked_sum, Accumulator, Overflow};
+
+/// Checks updates as they come, while rules are added and removed.
+///
+/// Each update keeps its number of broken rules. A rule change only looks at
+/// the updates containing both of its pages, so the verdicts of the other
+/// updates are never computed again.
+#[derive(Default)]
+pub struct OnlineChecker {
+    /// `(before, after)` pairs
+    rules: HashSet<(i32, i32)>,
+    updates: Vec<CheckedUpdate>,
+    /// Indexes of the updates containing each page
+    updates_by_page: HashMap<i32, Vec<usize>>,
+}
+
+struct CheckedUpdate {
+    pages: Vec<i32>,
+    /// Indexes of each page in the update
+    positions: BTreeMap<i32, Vec<usize>>,
+    nb_broken_rules: usize,
+}
+
+/// Update whose verdict was changed by a rule
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct VerdictChange {
+    pub update_index: usize,
+    pub is_valid: bool,
+}
+
+impl CheckedUpdate {
+    /// Number of times `after` is printed before `before`
+    fn count_breaks(&self, before: i32, after: i32) -> usize {
+        let (Some(before_positions), Some(after_positions)) =
+            (self.positions.get(&before), self.positions.get(&after))
+        else {
+            return 0;
+        };
+        before_positions
+            .iter()
+            .map(|before_position| {
+                after_positions
+                    .iter()
+                    .filter(|&after_position| after_position < before_position)
+                    .count()
+            })
+            .sum()
+    }
+}
+
+impl OnlineChecker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the updates that were valid and are not anymore. Nothing
+    /// changes if the rule is already known.
+    pub fn add_rule(&mut self, before: i32, after: i32) -> Vec<VerdictChange> {
+        if !self.rules.insert((before, after)) {
+            return Vec::new();
+        }
+        self.update_verdicts(before, after, |update, nb_breaks| {
+            update.nb_broken_rules += nb_breaks;
+        })
+    }
+
+    /// Returns the updates that were invalid and are now valid. Nothing
+    /// changes if the rule is unknown.
+    pub fn remove_rule(&mut self, before: i32, after: i32) -> Vec<VerdictChange> {
+        if !self.rules.remove(&(before, after)) {
+            return Vec::new();
+        }
+        self.update_verdicts(before, after, |update, nb_breaks| {
+            update.nb_broken_rules -= nb_breaks;
+        })
+    }
+
+    /// Returns the index of the update, and whether it follows the current
+    /// rules
+    pub fn add_update(&mut self, pages: Vec<i32>) -> (usize, bool) {
+        let update_index = self.updates.len();
+        let mut positions: BTreeMap<i32, Vec<usize>> = BTreeMap::new();
+        for (position, &page) in pages.iter().enumerate() {
+            positions.entry(page).or_default().push(position);
+        }
+        for &page in positions.keys() {
+            self.updates_by_page
+                .entry(page)
+                .or_default()
+                .push(update_index);
+        }
+
+        let nb_broken_rules = (0..pages.len())
+            .flat_map(|i| (i + 1..pages.len()).map(move |j| (i, j)))
+            .filter(|&(i, j)| self.rules.contains(&(pages[j], pages[i])))
+            .count();
+        self.updates.push(CheckedUpdate {
+            pages,
+            positions,
+            nb_broken_rules,
+        });
+        (update_index, nb_broken_rules == 0)
+    }
+
+    pub fn get_nb_updates(&self) -> usize {
+        self.updates.len()
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - There is no update at this index
+    pub fn is_valid(&self, update_index: usize) -> bool {
+        self.updates[update_index].nb_broken_rules == 0
+    }
+
+    /// Sum of the middle pages of the valid updates, the part 1 answer. Empty
+    /// updates have no middle page, and add nothing.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error, with the update and its index, if:
+    /// - The sum overflows `A`
+    pub fn get_valid_middle_sum<A: Accumulator>(&self) -> Result<A, Overflow> {
+        checked_sum(
+            self.updates
+                .iter()
+                .map(|update| match update.pages.get(update.pages.len() / 2) {
+                    Some(&middle) if update.nb_broken_rules == 0 => A::from_i64(middle.into()),
+                    _ => Some(A::zero()),
+                }),
+            |index| {
+                let pages: Vec<String> = self.updates[index]
+                    .pages
+                    .iter()
+                    .map(i32::to_string)
+                    .collect();
+                format!("update {}", pages.join(","))
+            },
+        )
+    }
+
+    fn update_verdicts(
+        &mut self,
+        before: i32,
+        after: i32,
+        apply: impl Fn(&mut CheckedUpdate, usize),
+    ) -> Vec<VerdictChange> {
+        let (Some(before_updates), Some(after_updates)) = (
+            self.updates_by_page.get(&before),
+            self.updates_by_page.get(&after),
+        ) else {
+            return Vec::new();
+        };
+        let after_updates: HashSet<usize> = after_updates.iter().copied().collect();
+
+        let mut changes = Vec::new();
+        for &update_index in before_updates {
+            if !after_updates.contains(&update_index) {
+                continue;
+            }
+            let update = &mut self.updates[update_index];
+            let nb_breaks = update.count_breaks(before, after);
+            if nb_breaks == 0 {
+                continue;
+            }
+            let was_valid = update.nb_broken_rules == 0;
+            apply(update, nb_breaks);
+            let is_valid = update.nb_broken_rules == 0;
+            if is_valid != was_valid {
+                changes.push(VerdictChange {
+                    update_index,
+                    is_valid,
+                });
+            }
+        }
+        changes
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+/// Pages linked by the rules, each page pointing to the pages that must come
+/// after it
+pub struct RuleGraph {
+    successors: BTreeMap<i32, BTreeSet<i32>>,
+}
+
+pub enum PageOrder {
+    /// Every page, each one before all the pages the rules put after it
+    Linear(Vec<i32>),
+    /// Some pages must come before themselves, through a chain of rules
+    Cyclic(Condensation),
+}
+
+/// Pages that are all before and after each other
+#[derive(PartialEq, Eq, Debug)]
+pub struct Component {
+    /// Sorted
+    pub pages: Vec<i32>,
+    /// A single page is only cyclic if a rule puts it before itself
+    pub is_cyclic: bool,
+}
+
+/// Strongly connected components of the rules, which are ordered even when
+/// the pages are not
+pub struct Condensation {
+    /// In an order where each component comes before its successors
+    pub components: Vec<Component>,
+    /// Links between components, as indexes in `components`
+    pub edges: BTreeSet<(usize, usize)>,
+}
+
+impl RuleGraph {
+    /// Rules are `(before, after)` pairs
+    pub fn new(rules: impl IntoIterator<Item = (i32, i32)>) -> Self {
+        let mut successors: BTreeMap<i32, BTreeSet<i32>> = BTreeMap::new();
+        for (before, after) in rules {
+            successors.entry(before).or_default().insert(after);
+            successors.entry(after).or_default();
+        }
+        Self { successors }
+    }
+
+    /// Ties are broken by the smallest page first, so the order is stable
+    pub fn get_page_order(&self) -> PageOrder {
+        let condensation = self.get_condensation();
+        if condensation
+            .components
+            .iter()
+            .any(|component| component.is_cyclic)
+        {
+            return PageOrder::Cyclic(condensation);
+        }
+        let pages = condensation
+            .components
+            .into_iter()
+            .flat_map(|component| component.pages)
+            .collect();
+        PageOrder::Linear(pages)
+    }
+
+    pub fn get_condensation(&self) -> Condensation {
+        let component_list = self.get_strongly_connected_components();
+        let mut component_of: BTreeMap<i32, usize> = BTreeMap::new();
+        for (index, pages) in component_list.iter().enumerate() {
+            for &page in pages {
+                component_of.insert(page, index);
+            }
+        }
+
+        let mut edges = BTreeSet::new();
+        for (page, successors) in &self.successors {
+            for successor in successors {
+                let edge = (component_of[page], component_of[successor]);
+                if edge.0 != edge.1 {
+                    edges.insert(edge);
+                }
+            }
+        }
+
+        // Components are sorted by their smallest page, so the queue gives
+        // the smallest ready one first
+        let mut nb_predecessors = vec![0; component_list.len()];
+        for &(_, to) in &edges {
+            nb_predecessors[to] += 1;
+        }
+        let mut ready: BTreeSet<usize> = (0..component_list.len())
+            .filter(|&index| nb_predecessors[index] == 0)
+            .collect();
+        let mut new_indexes = vec![0; component_list.len()];
+        let mut nb_sorted = 0;
+        while let Some(index) = ready.pop_first() {
+            new_indexes[index] = nb_sorted;
+            nb_sorted += 1;
+            for &(_, to) in edges.range((index, 0)..=(index, usize::MAX)) {
+                nb_predecessors[to] -= 1;
+                if nb_predecessors[to] == 0 {
+                    ready.insert(to);
+                }
+            }
+        }
+
+        let mut components: Vec<Option<Component>> =
+            (0..component_list.len()).map(|_| None).collect();
+        for (index, pages) in component_list.into_iter().enumerate() {
+            let is_cyclic = pages.len() > 1 || self.successors[&pages[0]].contains(&pages[0]);
+            components[new_indexes[index]] = Some(Component { pages, is_cyclic });
+        }
+        Condensation {
+            components: components.into_iter().map(Option::unwrap).collect(),
+            edges: edges
+                .into_iter()
+                .map(|(from, to)| (new_indexes[from], new_indexes[to]))
+                .collect(),
+        }
+    }
+
+    /// Graphviz graph of the rules. Each cycle is boxed in red with its rules,
+    /// those are the rules that no ordering of its pages can follow.
+    pub fn to_dot(&self) -> String {
+        let condensation = self.get_condensation();
+        let mut cycle_of: BTreeMap<i32, usize> = BTreeMap::new();
+        let mut output = String::from("digraph rules {\n");
+        for (index, component) in condensation.components.iter().enumerate() {
+            if !component.is_cyclic {
+                continue;
+            }
+            let pages: Vec<String> = component.pages.iter().map(i32::to_string).collect();
+            writeln!(output, "  subgraph cluster_{index} {{").unwrap();
+            writeln!(output, "    label=\"cycle of {} pages\";", pages.len()).unwrap();
+            writeln!(output, "    color=red;").unwrap();
+            writeln!(output, "    {};", pages.join("; ")).unwrap();
+            writeln!(output, "  }}").unwrap();
+            for &page in &component.pages {
+                cycle_of.insert(page, index);
+            }
+        }
+
+        for page in self.successors.keys() {
+            if !cycle_of.contains_key(page) {
+                writeln!(output, "  {page};").unwrap();
+            }
+        }
+        for (page, successors) in &self.successors {
+            for successor in successors {
+                let cycle = cycle_of.get(page);
+                if cycle.is_some() && cycle == cycle_of.get(successor) {
+                    writeln!(output, "  {page} -> {successor} [color=red];").unwrap();
+                } else {
+                    writeln!(output, "  {page} -> {successor};").unwrap();
+                }
+            }
+        }
+        output.push_str("}\n");
+        output
+    }
+
+    /// Tarjan's algorithm, the components are sorted by their smallest page
+    fn get_strongly_connected_components(&self) -> Vec<Vec<i32>> {
+        let mut search = ComponentSearch {
+            successors: &self.successors,
+            indexes: BTreeMap::new(),
+            low_links: BTreeMap::new(),
+            stack: Vec::new(),
+            on_stack: BTreeSet::new(),
+            components: Vec::new(),
+        };
+        for &page in self.successors.keys() {
+            if !search.indexes.contains_key(&page) {
+                search.visit(page);
+            }
+        }
+
+        let mut components = search.components;
+        for pages in &mut components {
+            pages.sort_unstable();
+        }
+        components.sort_unstable();
+        components
+    }
+}
+
+struct ComponentSearch<'a> {
+    successors: &'a BTreeMap<i32, BTreeSet<i32>>,
+    /// Visit order of each page
+    indexes: BTreeMap<i32, usize>,
+    /// Smallest visit order reachable from each page while on the stack
+    low_links: BTreeMap<i32, usize>,
+    stack: Vec<i32>,
+    on_stack: BTreeSet<i32>,
+    components: Vec<Vec<i32>>,
+}
+
+impl ComponentSearch<'_> {
+    /// Iterative, with the successors left to visit of each page on an
+    /// explicit stack, so that long chains of rules cannot overflow the call
+    /// stack
+    fn visit(&mut self, root: i32) {
+        let successors = self.successors;
+        self.enter(root);
+        let mut calls = vec![(root, successors[&root].iter())];
+        while let Some((page, remaining)) = calls.last_mut() {
+            let page = *page;
+            if let Some(&successor) = remaining.next() {
+                if !self.indexes.contains_key(&successor) {
+                    self.enter(successor);
+                    calls.push((successor, successors[&successor].iter()));
+                } else if self.on_stack.contains(&successor) {
+                    let low_link = self.low_links[&page].min(self.indexes[&successor]);
+                    self.low_links.insert(page, low_link);
+                }
+                continue;
+            }
+
+            calls.pop();
+            if let Some(&(parent, _)) = calls.last() {
+                let low_link = self.low_links[&parent].min(self.low_links[&page]);
+                self.low_links.insert(parent, low_link);
+            }
+            if self.low_links[&page] == self.indexes[&page] {
+                let mut component = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack.remove(&member);
+                    component.push(member);
+                    if member == page {
+                        break;
+                    }
+                }
+                self.components.push(component);
+            }
+        }
+    }
+
+    fn enter(&mut self, page: i32) {
+        let index = self.indexes.len();
+        self.indexes.insert(page, index);
+        self.low_links.insert(page, index);
+        self.stack.push(page);
+        self.on_stack.insert(page);
+    }
+}
@@ -0,0 +1,7 @@
+#![warn(clippy::pedantic)]
+#![allow(clippy::must_use_candidate)]
+
+pub mod checker;
+pub mod graph;
+pub mod helpers;
+pub mod solver;
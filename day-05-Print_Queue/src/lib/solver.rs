@@ -0,0 +1,201 @@
+use std::{cmp::Ordering, collections::BTreeMap};
+
+use aoc2024_common::accumulator::{checked_sum, Accumulator, Overflow, Wide};
+use aoc2024_common::day::Day;
+use aoc2024_common::exec::Executor;
+use tracing::instrument;
+
+use crate::graph::RuleGraph;
+
+fn get_middle_number(update: &[i32]) -> i32 {
+    let len = update.len();
+    let mid_index = len / 2;
+    update[mid_index]
+}
+
+/// Rules involving a page
+#[derive(PartialEq, Eq, Debug)]
+pub struct PageRules {
+    /// Pages that must be printed before it, sorted
+    pub before: Vec<i32>,
+    /// Pages that must be printed after it, sorted
+    pub after: Vec<i32>,
+}
+
+#[derive(Debug)]
+pub struct PrintQueue {
+    previous_map: BTreeMap<i32, Vec<i32>>,
+    updates: Vec<Vec<i32>>,
+}
+
+impl PrintQueue {
+    pub fn get_rule_graph(&self) -> RuleGraph {
+        RuleGraph::new(
+            self.previous_map
+                .iter()
+                .flat_map(|(&after, previous_list)| {
+                    previous_list.iter().map(move |&before| (before, after))
+                }),
+        )
+    }
+
+    pub fn get_updates(&self) -> &[Vec<i32>] {
+        &self.updates
+    }
+
+    pub fn get_rules_for(&self, page: i32) -> PageRules {
+        let mut before = self.previous_map.get(&page).cloned().unwrap_or_default();
+        let mut after: Vec<i32> = self
+            .previous_map
+            .iter()
+            .filter(|(_, previous_list)| previous_list.contains(&page))
+            .map(|(&after, _)| after)
+            .collect();
+        before.sort_unstable();
+        before.dedup();
+        after.sort_unstable();
+        PageRules { before, after }
+    }
+
+    /// Sum of the middle pages of the correctly ordered updates
+    ///
+    /// # Errors
+    ///
+    /// Will return an error, with the update and its index, if:
+    /// - The sum overflows `A`
+    #[instrument(level = "debug", skip_all)]
+    pub fn sum_valid_middles<A: Accumulator>(&self, executor: &Executor) -> Result<A, Overflow> {
+        let middles = executor.map(&self.updates, |update| {
+            if self.is_correctly_ordered(update) {
+                get_middle_number(update)
+            } else {
+                0
+            }
+        });
+        self.sum_middles(&middles)
+    }
+
+    /// Sum of the middle pages of the incorrectly ordered updates, once fixed
+    ///
+    /// # Errors
+    ///
+    /// Will return an error, with the update and its index, if:
+    /// - The sum overflows `A`
+    #[instrument(level = "debug", skip_all)]
+    pub fn sum_fixed_middles<A: Accumulator>(&self, executor: &Executor) -> Result<A, Overflow> {
+        let middles = executor.map(&self.updates, |update| {
+            if self.is_correctly_ordered(update) {
+                return 0;
+            }
+            get_middle_number(&self.get_fixed_update(update))
+        });
+        self.sum_middles(&middles)
+    }
+
+    fn sum_middles<A: Accumulator>(&self, middles: &[i32]) -> Result<A, Overflow> {
+        checked_sum(
+            middles.iter().map(|&middle| A::from_i64(middle.into())),
+            |index| {
+                let pages: Vec<String> = self.updates[index].iter().map(i32::to_string).collect();
+                format!("update {}", pages.join(","))
+            },
+        )
+    }
+
+    fn new() -> Self {
+        Self {
+            previous_map: BTreeMap::new(),
+            updates: Vec::new(),
+        }
+    }
+
+    fn process_line(&mut self, line: &str) {
+        if line.contains('|') {
+            self.process_ordering(line);
+        } else if line.contains(',') {
+            self.process_update(line);
+        }
+    }
+
+    fn process_ordering(&mut self, line: &str) {
+        let mut numbers = line.split('|').map(|x| x.parse::<i32>().unwrap());
+        let first = numbers.next().unwrap();
+        let second = numbers.next().unwrap();
+
+        let vec = self.previous_map.entry(second).or_default();
+        vec.push(first);
+    }
+
+    fn process_update(&mut self, line: &str) {
+        let update: Vec<i32> = line.split(',').map(|x| x.parse::<i32>().unwrap()).collect();
+        self.updates.push(update);
+    }
+
+    pub fn is_correctly_ordered(&self, update: &[i32]) -> bool {
+        (0..update.len()).all(|i| {
+            let tested_entry = update[i];
+            if let Some(previous_list) = self.previous_map.get(&tested_entry) {
+                let next_entries = &update[i + 1..];
+                previous_list
+                    .iter()
+                    .all(|previous_item| !next_entries.contains(previous_item))
+            } else {
+                true
+            }
+        })
+    }
+
+    /// The pages of the update, ordered by the rules
+    pub fn get_fixed_update(&self, update: &[i32]) -> Vec<i32> {
+        let mut update = update.to_vec();
+        self.fix_update_ordering(&mut update);
+        update
+    }
+
+    #[instrument(level = "trace", skip_all)]
+    fn fix_update_ordering(&self, update: &mut [i32]) {
+        update.sort_by(|a, b| {
+            if let Some(previous_list_a) = self.previous_map.get(a) {
+                if previous_list_a.contains(b) {
+                    return Ordering::Greater;
+                }
+            }
+
+            if let Some(previous_list_b) = self.previous_map.get(b) {
+                if previous_list_b.contains(a) {
+                    return Ordering::Less;
+                }
+            }
+
+            Ordering::Equal
+        });
+    }
+}
+
+pub struct Day05;
+
+impl Day for Day05 {
+    type Parsed<'a> = PrintQueue;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        let mut print_queue = PrintQueue::new();
+        input
+            .lines()
+            .for_each(|line| print_queue.process_line(line));
+        print_queue
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>, executor: &Executor) -> String {
+        parsed
+            .sum_valid_middles::<Wide>(executor)
+            .unwrap_or_else(|overflow| panic!("{overflow}"))
+            .to_string()
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>, executor: &Executor) -> String {
+        parsed
+            .sum_fixed_middles::<Wide>(executor)
+            .unwrap_or_else(|overflow| panic!("{overflow}"))
+            .to_string()
+    }
+}
@@ -0,0 +1,8 @@
+#![warn(clippy::pedantic)]
+
+use aoc2024_common::day::run_from_args_file;
+use aoc2024_day_05::solver::Day05;
+
+fn main() {
+    run_from_args_file::<Day05>();
+}
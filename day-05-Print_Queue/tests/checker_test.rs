@@ -0,0 +1,198 @@
+const RULES: &[(i32, i32)] = &[
+    (47, 53),
+    (97, 13),
+    (97, 61),
+    (97, 47),
+    (75, 29),
+    (61, 13),
+    (75, 53),
+    (29, 13),
+    (97, 29),
+    (53, 29),
+    (61, 53),
+    (97, 53),
+    (61, 29),
+    (47, 13),
+    (75, 47),
+    (97, 75),
+    (47, 61),
+    (75, 61),
+    (47, 29),
+    (75, 13),
+    (53, 13),
+];
+
+const UPDATES: &[&[i32]] = &[
+    &[75, 47, 61, 53, 29],
+    &[97, 61, 53, 29, 13],
+    &[75, 29, 13],
+    &[75, 97, 47, 61, 53],
+    &[61, 13, 29],
+    &[97, 13, 75, 29, 47],
+];
+
+mod add_update {
+    use super::{RULES, UPDATES};
+    use aoc2024_day_05::checker::OnlineChecker;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let mut checker = OnlineChecker::new();
+        for &(before, after) in RULES {
+            checker.add_rule(before, after);
+        }
+
+        // When
+        let verdicts: Vec<bool> = UPDATES
+            .iter()
+            .map(|update| checker.add_update(update.to_vec()).1)
+            .collect();
+
+        // Then
+        assert_eq!(verdicts, vec![true, true, true, false, false, false]);
+        assert_eq!(checker.get_valid_middle_sum::<i32>(), Ok(143));
+    }
+}
+
+mod add_rule {
+    use super::{RULES, UPDATES};
+    use aoc2024_day_05::checker::{OnlineChecker, VerdictChange};
+
+    #[test]
+    fn rules_after_the_updates() {
+        // Given
+        let mut checker = OnlineChecker::new();
+        for update in UPDATES {
+            checker.add_update(update.to_vec());
+        }
+
+        // When
+        let changes: Vec<VerdictChange> = RULES
+            .iter()
+            .flat_map(|&(before, after)| checker.add_rule(before, after))
+            .collect();
+
+        // Then
+        let invalidated: Vec<usize> = changes.iter().map(|change| change.update_index).collect();
+        assert_eq!(invalidated, vec![4, 5, 3]);
+        assert!(changes.iter().all(|change| !change.is_valid));
+        assert_eq!(checker.get_valid_middle_sum::<i32>(), Ok(143));
+    }
+
+    #[test]
+    fn known_rule() {
+        // Given
+        let mut checker = OnlineChecker::new();
+        checker.add_update(vec![2, 1]);
+        checker.add_rule(1, 2);
+
+        // When
+        let changes = checker.add_rule(1, 2);
+
+        // Then
+        assert!(changes.is_empty());
+        assert!(!checker.is_valid(0));
+    }
+}
+
+mod remove_rule {
+    use aoc2024_day_05::checker::{OnlineChecker, VerdictChange};
+
+    #[test]
+    fn update_valid_again() {
+        // Given
+        let mut checker = OnlineChecker::new();
+        checker.add_rule(1, 2);
+        checker.add_rule(1, 3);
+        checker.add_update(vec![3, 2, 1]);
+        checker.add_update(vec![2, 1, 4]);
+
+        // When
+        let first_changes = checker.remove_rule(1, 3);
+        let second_changes = checker.remove_rule(1, 2);
+
+        // Then
+        assert!(first_changes.is_empty());
+        assert_eq!(
+            second_changes,
+            vec![
+                VerdictChange {
+                    update_index: 0,
+                    is_valid: true
+                },
+                VerdictChange {
+                    update_index: 1,
+                    is_valid: true
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_rule() {
+        // Given
+        let mut checker = OnlineChecker::new();
+        checker.add_update(vec![2, 1]);
+
+        // When
+        let changes = checker.remove_rule(1, 2);
+
+        // Then
+        assert!(changes.is_empty());
+        assert!(checker.is_valid(0));
+    }
+}
+
+mod get_valid_middle_sum {
+    use aoc2024_common::accumulator::Overflow;
+    use aoc2024_day_05::checker::OnlineChecker;
+
+    #[test]
+    fn overflow() {
+        // Given
+        let mut checker = OnlineChecker::new();
+        checker.add_update(vec![1, 2_000_000_000, 3]);
+        checker.add_update(vec![2_000_000_000]);
+
+        // When
+        let result = checker.get_valid_middle_sum::<i32>();
+
+        // Then
+        assert_eq!(
+            result,
+            Err(Overflow {
+                index: 1,
+                item: String::from("update 2000000000")
+            })
+        );
+    }
+
+    #[test]
+    fn empty_update() {
+        // Given
+        let mut checker = OnlineChecker::new();
+        checker.add_update(vec![75, 47, 61]);
+
+        // When
+        let (_, is_valid) = checker.add_update(Vec::new());
+
+        // Then
+        assert!(is_valid);
+        assert_eq!(checker.get_valid_middle_sum::<i32>(), Ok(47));
+    }
+
+    #[test]
+    fn wider_accumulator() {
+        // Given
+        let mut checker = OnlineChecker::new();
+        checker.add_update(vec![1, 2_000_000_000, 3]);
+        checker.add_update(vec![2_000_000_000]);
+
+        // When
+        let result = checker.get_valid_middle_sum::<i64>();
+
+        // Then
+        assert_eq!(result, Ok(4_000_000_000));
+    }
+}
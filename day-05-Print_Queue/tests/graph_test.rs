@@ -0,0 +1,122 @@
+mod get_page_order {
+    use aoc2024_common::day::Day;
+    use aoc2024_day_05::graph::{Component, PageOrder, RuleGraph};
+    use aoc2024_day_05::solver::Day05;
+
+    #[test]
+    fn acyclic_rules() {
+        // Given
+        let input = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n53|29\n\
+                     61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n\
+                     53|13\n\n75,47,61,53,29\n";
+        let graph = Day05::parse(input).get_rule_graph();
+
+        // When
+        let result = graph.get_page_order();
+
+        // Then
+        let PageOrder::Linear(pages) = result else {
+            panic!("The rules are acyclic");
+        };
+        assert_eq!(pages, vec![97, 75, 47, 61, 53, 29, 13]);
+    }
+
+    #[test]
+    fn ties_go_to_the_smallest_page() {
+        // Given
+        let graph = RuleGraph::new([(5, 1), (3, 1), (4, 2)]);
+
+        // When
+        let result = graph.get_page_order();
+
+        // Then
+        let PageOrder::Linear(pages) = result else {
+            panic!("The rules are acyclic");
+        };
+        assert_eq!(pages, vec![3, 4, 2, 5, 1]);
+    }
+
+    #[test]
+    fn cyclic_rules() {
+        // Given
+        let graph = RuleGraph::new([(1, 2), (2, 3), (3, 1), (3, 4), (0, 2), (5, 5)]);
+
+        // When
+        let result = graph.get_page_order();
+
+        // Then
+        let PageOrder::Cyclic(condensation) = result else {
+            panic!("The rules have cycles");
+        };
+        assert_eq!(
+            condensation.components,
+            vec![
+                Component {
+                    pages: vec![0],
+                    is_cyclic: false
+                },
+                Component {
+                    pages: vec![1, 2, 3],
+                    is_cyclic: true
+                },
+                Component {
+                    pages: vec![4],
+                    is_cyclic: false
+                },
+                Component {
+                    pages: vec![5],
+                    is_cyclic: true
+                },
+            ]
+        );
+        assert_eq!(
+            condensation.edges.into_iter().collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn long_cycle() {
+        // Given
+        let graph = RuleGraph::new((0..100_000).map(|page| (page, (page + 1) % 100_000)));
+
+        // When
+        let result = graph.get_page_order();
+
+        // Then
+        let PageOrder::Cyclic(condensation) = result else {
+            panic!("The rules have cycles");
+        };
+        assert_eq!(condensation.components.len(), 1);
+        assert_eq!(condensation.components[0].pages.len(), 100_000);
+    }
+}
+
+mod to_dot {
+    use aoc2024_day_05::graph::RuleGraph;
+
+    #[test]
+    fn cycles_are_boxed() {
+        // Given
+        let graph = RuleGraph::new([(1, 2), (2, 1), (2, 3)]);
+
+        // When
+        let result = graph.to_dot();
+
+        // Then
+        assert_eq!(
+            result,
+            "digraph rules {\n\
+             \x20 subgraph cluster_0 {\n\
+             \x20   label=\"cycle of 2 pages\";\n\
+             \x20   color=red;\n\
+             \x20   1; 2;\n\
+             \x20 }\n\
+             \x20 3;\n\
+             \x20 1 -> 2 [color=red];\n\
+             \x20 2 -> 1 [color=red];\n\
+             \x20 2 -> 3;\n\
+             }\n"
+        );
+    }
+}
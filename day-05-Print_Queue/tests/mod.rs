@@ -0,0 +1,7 @@
+#![warn(clippy::pedantic)]
+
+pub mod checker_test;
+pub mod graph_test;
+pub mod helpers_test;
+pub mod snapshot_test;
+pub mod solver_test;
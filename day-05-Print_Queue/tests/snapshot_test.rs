@@ -0,0 +1,79 @@
+const PUZZLE_SUBJECT_EXAMPLE: &[&str] = &[
+    "47|53",
+    "97|13",
+    "97|61",
+    "97|47",
+    "75|29",
+    "61|13",
+    "75|53",
+    "29|13",
+    "97|29",
+    "53|29",
+    "61|53",
+    "97|53",
+    "61|29",
+    "47|13",
+    "75|47",
+    "97|75",
+    "47|61",
+    "75|61",
+    "47|29",
+    "75|13",
+    "53|13",
+    "",
+    "75,47,61,53,29",
+    "97,61,53,29,13",
+    "75,29,13",
+    "75,97,47,61,53",
+    "61,13,29",
+    "97,13,75,29,47",
+];
+
+mod parse {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::assert_named_snapshot;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_05::solver::Day05;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+
+        // When
+        let result = Day05::parse(&input);
+
+        // Then
+        assert_named_snapshot!("parse", result);
+    }
+}
+
+mod get_fixed_update {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::assert_named_snapshot;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_05::solver::Day05;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let print_queue = Day05::parse(&input);
+
+        // When
+        let result: Vec<(&[i32], bool, Vec<i32>)> = print_queue
+            .get_updates()
+            .iter()
+            .map(|update| {
+                (
+                    update.as_slice(),
+                    print_queue.is_correctly_ordered(update),
+                    print_queue.get_fixed_update(update),
+                )
+            })
+            .collect();
+
+        // Then
+        assert_named_snapshot!("fixed_updates", result);
+    }
+}
@@ -0,0 +1,149 @@
+const PUZZLE_SUBJECT_EXAMPLE: &[&str] = &[
+    "47|53",
+    "97|13",
+    "97|61",
+    "97|47",
+    "75|29",
+    "61|13",
+    "75|53",
+    "29|13",
+    "97|29",
+    "53|29",
+    "61|53",
+    "97|53",
+    "61|29",
+    "47|13",
+    "75|47",
+    "97|75",
+    "47|61",
+    "75|61",
+    "47|29",
+    "75|13",
+    "53|13",
+    "",
+    "75,47,61,53,29",
+    "97,61,53,29,13",
+    "75,29,13",
+    "75,97,47,61,53",
+    "61,13,29",
+    "97,13,75,29,47",
+];
+
+mod part1 {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::day::solve_part1;
+    use aoc2024_day_05::solver::Day05;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // When
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+
+        // When
+        let result = solve_part1::<Day05>(&input);
+
+        // Then
+        assert_eq!(result, "143");
+    }
+}
+
+mod part2 {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::day::solve_part2;
+    use aoc2024_day_05::solver::Day05;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // When
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+
+        // When
+        let result = solve_part2::<Day05>(&input);
+
+        // Then
+        assert_eq!(result, "123");
+    }
+}
+
+mod sum_valid_middles {
+    use aoc2024_common::accumulator::Overflow;
+    use aoc2024_common::day::Day;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_day_05::solver::Day05;
+
+    const INPUT: &str = "1|2147483647\n\n1,2147483647,2\n3,2147483647,4\n";
+
+    #[test]
+    fn overflowing_update() {
+        // Given
+        let print_queue = Day05::parse(INPUT);
+
+        // When
+        let result = print_queue.sum_valid_middles::<i32>(&Executor::sequential());
+
+        // Then
+        assert_eq!(
+            result,
+            Err(Overflow {
+                index: 1,
+                item: String::from("update 3,2147483647,4")
+            })
+        );
+    }
+
+    #[test]
+    fn wider_accumulator() {
+        // Given
+        let print_queue = Day05::parse(INPUT);
+
+        // When
+        let result = print_queue.sum_valid_middles::<i64>(&Executor::sequential());
+
+        // Then
+        assert_eq!(result, Ok(2 * i64::from(i32::MAX)));
+    }
+}
+
+mod get_rules_for {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_05::solver::{Day05, PageRules};
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let print_queue = Day05::parse(&input);
+
+        // When
+        let result = print_queue.get_rules_for(53);
+
+        // Then
+        assert_eq!(
+            result,
+            PageRules {
+                before: vec![47, 61, 75, 97],
+                after: vec![13, 29],
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_page() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let print_queue = Day05::parse(&input);
+
+        // When
+        let result = print_queue.get_rules_for(1);
+
+        // Then
+        assert_eq!(
+            result,
+            PageRules {
+                before: vec![],
+                after: vec![],
+            }
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use std::fmt::Write;
+
+use crate::solver::{Coordinate, GuardLoop};
+
+const CSV_HEADER: &str =
+    "obstruction_x,obstruction_y,guard,cycle_length,entry_x,entry_y,entry_direction,cycle_cells";
+
+/// One line per loop, the cycle cells are `x:y` pairs separated by `;`
+pub fn to_csv(guard_loops: &[GuardLoop]) -> String {
+    let mut output = String::from(CSV_HEADER);
+    output.push('\n');
+    for guard_loop in guard_loops {
+        let cycle_cells: Vec<String> = guard_loop
+            .cycle_cells
+            .iter()
+            .map(|cell| format!("{}:{}", cell.x, cell.y))
+            .collect();
+        writeln!(
+            output,
+            "{},{},{},{},{},{},{},{}",
+            guard_loop.obstruction.x,
+            guard_loop.obstruction.y,
+            guard_loop.guard_index,
+            guard_loop.cycle_length,
+            guard_loop.entry_position.x,
+            guard_loop.entry_position.y,
+            guard_loop.entry_direction,
+            cycle_cells.join(";")
+        )
+        .unwrap();
+    }
+    output
+}
+
+pub fn to_json(guard_loops: &[GuardLoop]) -> String {
+    let entries: Vec<String> = guard_loops
+        .iter()
+        .map(|guard_loop| {
+            let cycle_cells: Vec<String> =
+                guard_loop.cycle_cells.iter().copied().map(coordinate_to_json).collect();
+            format!(
+                "  {{\"obstruction\": {}, \"guard\": {}, \"cycle_length\": {}, \"entry\": {{\"position\": {}, \"direction\": \"{}\"}}, \"cycle_cells\": [{}]}}",
+                coordinate_to_json(guard_loop.obstruction),
+                guard_loop.guard_index,
+                guard_loop.cycle_length,
+                coordinate_to_json(guard_loop.entry_position),
+                guard_loop.entry_direction,
+                cycle_cells.join(", ")
+            )
+        })
+        .collect();
+
+    if entries.is_empty() {
+        return String::from("[]\n");
+    }
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
+
+fn coordinate_to_json(coordinate: Coordinate) -> String {
+    format!("{{\"x\": {}, \"y\": {}}}", coordinate.x, coordinate.y)
+}
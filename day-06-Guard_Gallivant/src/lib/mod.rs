@@ -0,0 +1,8 @@
+#![warn(clippy::pedantic)]
+#![allow(clippy::must_use_candidate)]
+
+pub mod export;
+pub mod helpers;
+pub mod optimize;
+pub mod simulation;
+pub mod solver;
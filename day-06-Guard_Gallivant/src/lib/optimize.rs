@@ -0,0 +1,268 @@
+use std::collections::HashSet;
+use std::slice;
+use std::time::{Duration, Instant};
+
+use aoc2024_common::exec::Executor;
+
+use crate::solver::{Coordinate, GuardLoop, MapData, MapWalker};
+
+/// Candidates are tried by chunks, the budget is checked between them
+const CHUNK_SIZE: usize = 256;
+
+/// Time given to a search, which then returns its best answer so far
+#[derive(Copy, Clone)]
+pub struct Budget {
+    deadline: Option<Instant>,
+}
+
+impl Budget {
+    pub fn new(time_budget: Duration) -> Self {
+        Self {
+            deadline: Some(Instant::now() + time_budget),
+        }
+    }
+
+    pub fn unlimited() -> Self {
+        Self { deadline: None }
+    }
+
+    fn is_over(self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+pub struct SearchResult<T> {
+    /// `None` if there is no answer, or if none was found in time
+    pub best: Option<T>,
+    /// False if the budget ran out before the search was over
+    pub is_complete: bool,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Goal {
+    MostVisited,
+    FewestVisited,
+}
+
+/// Single obstruction after which the guard still leaves the map
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Placement {
+    pub obstruction: Coordinate,
+    pub nb_visited: usize,
+}
+
+/// Obstruction, on the walk of the guard, that gives the most or the fewest
+/// visited cells before the guard leaves. Ties go to the smallest coordinates.
+pub fn find_placement(
+    map_data: &MapData<'_>,
+    guard_index: usize,
+    goal: Goal,
+    executor: &Executor,
+    budget: Budget,
+    on_improvement: impl FnMut(&Placement),
+) -> SearchResult<Placement> {
+    let guard = map_data.get_guards()[guard_index];
+    let candidate_list = map_data.get_guard_candidates(guard_index, executor);
+
+    search_by_chunks(
+        &candidate_list,
+        executor,
+        budget,
+        |&obstruction| {
+            let mut candidate_map = MapWalker::new(map_data, guard, slice::from_ref(&obstruction))
+                .with_executor(executor);
+            let visited_list = candidate_map.walk_to_exit()?;
+            Some(Placement {
+                obstruction,
+                nb_visited: visited_list.len(),
+            })
+        },
+        |placement, best| match goal {
+            Goal::MostVisited => placement.nb_visited > best.nb_visited,
+            Goal::FewestVisited => placement.nb_visited < best.nb_visited,
+        },
+        on_improvement,
+    )
+}
+
+/// Obstruction that traps the guard in the longest loop, counted in moves and
+/// turns. Ties go to the smallest coordinates.
+pub fn find_longest_cycle(
+    map_data: &MapData<'_>,
+    guard_index: usize,
+    executor: &Executor,
+    budget: Budget,
+    on_improvement: impl FnMut(&GuardLoop),
+) -> SearchResult<GuardLoop> {
+    let guard = map_data.get_guards()[guard_index];
+    let candidate_list = map_data.get_guard_candidates(guard_index, executor);
+
+    search_by_chunks(
+        &candidate_list,
+        executor,
+        budget,
+        |&obstruction| {
+            let mut candidate_map = MapWalker::new(map_data, guard, slice::from_ref(&obstruction))
+                .with_executor(executor);
+            candidate_map.find_loop(guard_index)
+        },
+        |guard_loop, best| guard_loop.cycle_length > best.cycle_length,
+        on_improvement,
+    )
+}
+
+/// Smallest set of obstructions that keeps the guard in the map, sorted by
+/// coordinates.
+///
+/// A trap is first built by blocking the last new cell of the walk until the
+/// guard loops, as a best answer so far. Sets are then tried by increasing
+/// size, each obstruction being on the walk left by the previous ones, so the
+/// first trap found this way is minimal.
+pub fn find_minimal_trap(
+    map_data: &MapData<'_>,
+    guard_index: usize,
+    executor: &Executor,
+    budget: Budget,
+    mut on_improvement: impl FnMut(&[Coordinate]),
+) -> SearchResult<Vec<Coordinate>> {
+    let best = build_greedy_trap(map_data, guard_index, budget);
+    if let Some(trap) = &best {
+        on_improvement(trap);
+    }
+
+    let mut frontier: Vec<Vec<Coordinate>> = vec![Vec::new()];
+    let mut seen_sets: HashSet<Vec<Coordinate>> = HashSet::new();
+    while !frontier.is_empty() {
+        let size = frontier[0].len() + 1;
+        if best.as_ref().is_some_and(|trap| trap.len() <= size) {
+            break;
+        }
+
+        let mut next_frontier = Vec::new();
+        for chunk in frontier.chunks(CHUNK_SIZE) {
+            if budget.is_over() {
+                return SearchResult {
+                    best,
+                    is_complete: false,
+                };
+            }
+            let expansions = executor.map(chunk, |obstructions| {
+                expand_trap(map_data, guard_index, obstructions)
+            });
+            for expansion in expansions {
+                match expansion {
+                    Ok(trap) => {
+                        on_improvement(&trap);
+                        return SearchResult {
+                            best: Some(trap),
+                            is_complete: true,
+                        };
+                    }
+                    Err(larger_sets) => next_frontier.extend(
+                        larger_sets
+                            .into_iter()
+                            .filter(|obstructions| seen_sets.insert(obstructions.clone())),
+                    ),
+                }
+            }
+        }
+        frontier = next_frontier;
+    }
+
+    SearchResult {
+        best,
+        is_complete: true,
+    }
+}
+
+/// Returns the first trap made by adding one obstruction on the walk, or all
+/// the sets made this way if none traps the guard
+fn expand_trap(
+    map_data: &MapData<'_>,
+    guard_index: usize,
+    obstructions: &[Coordinate],
+) -> Result<Vec<Coordinate>, Vec<Vec<Coordinate>>> {
+    let guard = map_data.get_guards()[guard_index];
+    let Some(mut visited_list) = MapWalker::new(map_data, guard, obstructions).walk_to_exit()
+    else {
+        return Ok(obstructions.to_vec());
+    };
+    visited_list.sort_unstable();
+
+    let mut larger_sets = Vec::new();
+    for position in visited_list {
+        if !map_data.can_be_obstructed(position) {
+            continue;
+        }
+        let mut candidate = obstructions.to_vec();
+        candidate.push(position);
+        candidate.sort_unstable();
+        if MapWalker::new(map_data, guard, &candidate).walk_map() {
+            return Ok(candidate);
+        }
+        larger_sets.push(candidate);
+    }
+    Err(larger_sets)
+}
+
+fn build_greedy_trap(
+    map_data: &MapData<'_>,
+    guard_index: usize,
+    budget: Budget,
+) -> Option<Vec<Coordinate>> {
+    let guard = map_data.get_guards()[guard_index];
+    let mut obstructions = Vec::new();
+    loop {
+        let Some(visited_list) = MapWalker::new(map_data, guard, &obstructions).walk_to_exit()
+        else {
+            obstructions.sort_unstable();
+            return Some(obstructions);
+        };
+        if budget.is_over() {
+            return None;
+        }
+        let &last_position = visited_list
+            .iter()
+            .rev()
+            .find(|&&position| map_data.can_be_obstructed(position))?;
+        obstructions.push(last_position);
+    }
+}
+
+/// Keeps the first of the best results, in the candidates order
+fn search_by_chunks<T, R, F, B>(
+    candidate_list: &[T],
+    executor: &Executor,
+    budget: Budget,
+    evaluate: F,
+    is_better: B,
+    mut on_improvement: impl FnMut(&R),
+) -> SearchResult<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> Option<R> + Sync + Send,
+    B: Fn(&R, &R) -> bool,
+{
+    let mut best: Option<R> = None;
+    for chunk in candidate_list.chunks(CHUNK_SIZE) {
+        if budget.is_over() {
+            return SearchResult {
+                best,
+                is_complete: false,
+            };
+        }
+        for result in executor.map(chunk, &evaluate).into_iter().flatten() {
+            if best.as_ref().is_none_or(|best| is_better(&result, best)) {
+                on_improvement(&result);
+                best = Some(result);
+            }
+        }
+    }
+
+    SearchResult {
+        best,
+        is_complete: true,
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::solver::{Coordinate, Guard, MapData};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CollisionKind {
+    /// Both guards end the tick on the same cell
+    SameCell,
+    /// The guards swap their cells during the tick
+    Crossing,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Collision {
+    pub tick: usize,
+    pub guards: (usize, usize),
+    /// Cell of the first guard at the end of the tick
+    pub position: Coordinate,
+    pub kind: CollisionKind,
+}
+
+/// The joint state of the guards repeats every `period` ticks from `start`
+#[derive(PartialEq, Eq, Debug)]
+pub struct GlobalLoop {
+    pub start: usize,
+    pub period: usize,
+}
+
+pub struct SimulationReport {
+    pub nb_ticks: usize,
+    /// Tick at which each guard left the map, `None` if it never did
+    pub exits: Vec<Option<usize>>,
+    /// `None` if every guard left, or if the tick limit was hit first
+    pub global_loop: Option<GlobalLoop>,
+    /// Found until every guard left, or over one full period of the global loop
+    pub collisions: Vec<Collision>,
+    /// Cells visited by more than one guard, sorted by coordinates
+    pub contended_cells: Vec<Coordinate>,
+    /// Number of cells visited by at least one guard
+    pub coverage: usize,
+}
+
+/// Moves all the guards of a map at once, one move or turn per tick.
+///
+/// Guards do not block each other, their meetings are only reported.
+pub struct Simulation<'a> {
+    map_data: &'a MapData<'a>,
+    tick: usize,
+    /// `None` once the guard left the map
+    guards: Vec<Option<Guard>>,
+    exits: Vec<Option<usize>>,
+    visited_sets: Vec<HashSet<Coordinate>>,
+    states_histories: Vec<HashMap<Guard, usize>>,
+    /// Entry tick and period of the loop of each guard, once it repeated a state
+    cycles: Vec<Option<(usize, usize)>>,
+    global_loop: Option<GlobalLoop>,
+    collisions: Vec<Collision>,
+}
+
+impl<'a> Simulation<'a> {
+    pub fn new(map_data: &'a MapData<'a>) -> Self {
+        let guards = map_data.get_guards();
+        let nb_guards = guards.len();
+        Self {
+            map_data,
+            tick: 0,
+            guards: guards.iter().copied().map(Some).collect(),
+            exits: vec![None; nb_guards],
+            visited_sets: vec![HashSet::new(); nb_guards],
+            states_histories: vec![HashMap::new(); nb_guards],
+            cycles: vec![None; nb_guards],
+            global_loop: None,
+            collisions: Vec::new(),
+        }
+    }
+
+    /// Runs until every guard left the map, the global loop was fully seen, or
+    /// `max_ticks` ticks
+    pub fn run(mut self, max_ticks: usize) -> SimulationReport {
+        self.record_tick();
+        while self.tick < max_ticks && !self.is_over() {
+            self.step();
+            self.record_tick();
+        }
+
+        let coverage = self.get_coverage();
+        let contended_cells = self.get_contended_cells();
+        SimulationReport {
+            nb_ticks: self.tick,
+            exits: self.exits,
+            global_loop: self.global_loop,
+            collisions: self.collisions,
+            contended_cells,
+            coverage,
+        }
+    }
+
+    fn is_over(&self) -> bool {
+        if self.guards.iter().all(Option::is_none) {
+            return true;
+        }
+        match &self.global_loop {
+            // The next tick would be the same as the loop start
+            Some(GlobalLoop { start, period }) => self.tick + 1 >= start + period,
+            None => false,
+        }
+    }
+
+    fn step(&mut self) {
+        let previous_guards = self.guards.clone();
+        self.tick += 1;
+        for guard in self.guards.iter_mut().flatten() {
+            *guard = self.map_data.get_next_state(*guard, &[]);
+        }
+        self.detect_collisions(&previous_guards);
+    }
+
+    fn record_tick(&mut self) {
+        for index in 0..self.guards.len() {
+            let Some(guard) = self.guards[index] else {
+                continue;
+            };
+            if !self.map_data.is_in_map(guard.position) {
+                self.guards[index] = None;
+                self.exits[index] = Some(self.tick);
+                continue;
+            }
+
+            self.visited_sets[index].insert(guard.position);
+            if self.cycles[index].is_none() {
+                if let Some(&entry) = self.states_histories[index].get(&guard) {
+                    self.cycles[index] = Some((entry, self.tick - entry));
+                } else {
+                    self.states_histories[index].insert(guard, self.tick);
+                }
+            }
+        }
+
+        if self.global_loop.is_none() {
+            self.global_loop = self.find_global_loop();
+        }
+    }
+
+    /// Known once every guard still in the map is in its own loop
+    fn find_global_loop(&self) -> Option<GlobalLoop> {
+        let active_cycles: Vec<Option<(usize, usize)>> = self
+            .guards
+            .iter()
+            .zip(&self.cycles)
+            .filter(|(guard, _)| guard.is_some())
+            .map(|(_, &cycle)| cycle)
+            .collect();
+        if active_cycles.is_empty() {
+            return None;
+        }
+
+        let last_exit = self.exits.iter().flatten().copied().max().unwrap_or(0);
+        let mut start = last_exit;
+        let mut period = 1;
+        for cycle in active_cycles {
+            let (entry, guard_period) = cycle?;
+            start = start.max(entry);
+            period = get_least_common_multiple(period, guard_period)?;
+        }
+        Some(GlobalLoop { start, period })
+    }
+
+    fn detect_collisions(&mut self, previous_guards: &[Option<Guard>]) {
+        for first in 0..self.guards.len() {
+            for second in first + 1..self.guards.len() {
+                let (Some(first_guard), Some(second_guard)) =
+                    (self.guards[first], self.guards[second])
+                else {
+                    continue;
+                };
+                if !self.map_data.is_in_map(first_guard.position)
+                    || !self.map_data.is_in_map(second_guard.position)
+                {
+                    continue;
+                }
+
+                let kind = if first_guard.position == second_guard.position {
+                    CollisionKind::SameCell
+                } else if previous_guards[first].map(|guard| guard.position)
+                    == Some(second_guard.position)
+                    && previous_guards[second].map(|guard| guard.position)
+                        == Some(first_guard.position)
+                {
+                    CollisionKind::Crossing
+                } else {
+                    continue;
+                };
+                self.collisions.push(Collision {
+                    tick: self.tick,
+                    guards: (first, second),
+                    position: first_guard.position,
+                    kind,
+                });
+            }
+        }
+    }
+
+    fn get_coverage(&self) -> usize {
+        let covered_cells: HashSet<Coordinate> =
+            self.visited_sets.iter().flatten().copied().collect();
+        covered_cells.len()
+    }
+
+    fn get_contended_cells(&self) -> Vec<Coordinate> {
+        let mut nb_visitors: HashMap<Coordinate, usize> = HashMap::new();
+        for &position in self.visited_sets.iter().flatten() {
+            *nb_visitors.entry(position).or_default() += 1;
+        }
+
+        let mut contended_cells: Vec<Coordinate> = nb_visitors
+            .into_iter()
+            .filter(|&(_, count)| count > 1)
+            .map(|(position, _)| position)
+            .collect();
+        contended_cells.sort_unstable();
+        contended_cells
+    }
+}
+
+/// Returns `None` on overflow
+fn get_least_common_multiple(a: usize, b: usize) -> Option<usize> {
+    let mut gcd = a;
+    let mut remainder = b;
+    while remainder != 0 {
+        (gcd, remainder) = (remainder, gcd % remainder);
+    }
+    (a / gcd).checked_mul(b)
+}
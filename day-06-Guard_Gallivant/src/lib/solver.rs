@@ -0,0 +1,571 @@
+use aoc2024_common::day::Day;
+use aoc2024_common::exec::Executor;
+use aoc2024_common::grid::ByteGrid;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    hash::Hash,
+    slice,
+    sync::OnceLock,
+};
+use tracing::{debug_span, instrument};
+
+#[derive(Copy, Clone)]
+enum Tile {
+    Wall,
+    Open,
+    /// Can only be entered going in this direction, it blocks like a wall otherwise
+    OneWay(usize),
+    /// Moves the guard to the other teleporter with the same digit
+    Teleporter,
+}
+
+/// The map is checked by `MapParser`, guards stand on open tiles
+impl From<u8> for Tile {
+    fn from(value: u8) -> Self {
+        match value {
+            b'#' => Tile::Wall,
+            b'0'..=b'9' => Tile::Teleporter,
+            _ => match ONE_WAY_CHARS.iter().position(|&c| c == value) {
+                Some(direction_index) => Tile::OneWay(direction_index),
+                None => Tile::Open,
+            },
+        }
+    }
+}
+
+/// What a guard does when facing a wall
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum TurnRule {
+    #[default]
+    Right,
+    Left,
+    Reverse,
+}
+
+impl TurnRule {
+    fn get_shift(self) -> usize {
+        match self {
+            TurnRule::Right => 1,
+            TurnRule::Reverse => 2,
+            TurnRule::Left => 3,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Coordinate {
+    pub x: i32,
+    pub y: i32,
+}
+
+fn add_coordinates(a: Coordinate, b: Coordinate) -> Coordinate {
+    Coordinate {
+        x: a.x + b.x,
+        y: a.y + b.y,
+    }
+}
+
+const DIRECTIONS_LIST: [Coordinate; 4] = [
+    Coordinate { x: 0, y: -1 },
+    Coordinate { x: 1, y: 0 },
+    Coordinate { x: 0, y: 1 },
+    Coordinate { x: -1, y: 0 },
+];
+
+const DIRECTIONS_NAMES: [&str; 4] = ["up", "right", "down", "left"];
+const GUARD_CHARS: [u8; 4] = *b"^>v<";
+const ONE_WAY_CHARS: [u8; 4] = *b"NESW";
+
+/// Loop the guard gets stuck in once an obstruction is added
+#[derive(Debug)]
+pub struct GuardLoop {
+    pub obstruction: Coordinate,
+    /// Index of the trapped guard, in reading order
+    pub guard_index: usize,
+    /// Number of moves, turns included, before the guard is back in the same state
+    pub cycle_length: usize,
+    /// First position of the loop reached by the guard
+    pub entry_position: Coordinate,
+    pub entry_direction: &'static str,
+    pub cycle_cells: BTreeSet<Coordinate>,
+}
+
+/// Path of one guard, for a given set of added obstructions
+#[derive(PartialEq, Eq, Debug)]
+pub struct Walk {
+    /// In the order of their first visit
+    pub visited: Vec<Coordinate>,
+    /// True if the guard never leaves the map
+    pub is_loop: bool,
+}
+
+/// Besides `#` and `.`, a map can hold:
+/// - Guards, starting up, right, down or left: `^`, `>`, `v`, `<`
+/// - One-way tiles, only entered going up, right, down or left: `N`, `E`, `S`, `W`
+/// - Teleporters, pairs of the same digit: stepping on one puts the guard on the
+///   other, keeping its direction
+struct MapParser<'a> {
+    map: ByteGrid<'a>,
+    guards: Vec<Guard>,
+    teleporters: BTreeMap<u8, Vec<Coordinate>>,
+}
+
+impl<'a> MapParser<'a> {
+    fn new(input: &'a str) -> Self {
+        MapParser {
+            map: ByteGrid::new(input),
+            guards: Vec::new(),
+            teleporters: BTreeMap::new(),
+        }
+    }
+
+    fn parse_row(&mut self, y: usize) {
+        for (x, &c) in self.map.row(y).iter().enumerate() {
+            let y = i32::try_from(y).unwrap();
+            let x = i32::try_from(x).unwrap();
+            let position = Coordinate { x, y };
+            match c {
+                b'#' | b'.' => {}
+                b'0'..=b'9' => self.teleporters.entry(c).or_default().push(position),
+                _ if ONE_WAY_CHARS.contains(&c) => {}
+                _ => {
+                    let direction_index = GUARD_CHARS
+                        .iter()
+                        .position(|&guard_char| guard_char == c)
+                        .unwrap_or_else(|| panic!("Invalid character in map {}", char::from(c)));
+                    self.guards.push(Guard::new(position, direction_index));
+                }
+            }
+        }
+    }
+
+    fn get_map_data(mut self) -> MapData<'a> {
+        for y in 0..self.map.get_height() {
+            self.parse_row(y);
+        }
+        assert!(!self.guards.is_empty(), "No guard found in data");
+
+        let mut teleporters = HashMap::new();
+        for (digit, positions) in self.teleporters {
+            let &[a, b] = positions.as_slice() else {
+                panic!("Teleporter {} needs exactly two tiles", char::from(digit));
+            };
+            teleporters.insert(a, b);
+            teleporters.insert(b, a);
+        }
+
+        MapData {
+            map: self.map,
+            guards: self.guards,
+            teleporters,
+            turn_rule: TurnRule::default(),
+            visited_sets: OnceLock::new(),
+        }
+    }
+}
+
+pub struct MapData<'a> {
+    map: ByteGrid<'a>,
+    guards: Vec<Guard>,
+    teleporters: HashMap<Coordinate, Coordinate>,
+    turn_rule: TurnRule,
+    /// Cells visited by each guard without any added obstruction, shared by both parts
+    visited_sets: OnceLock<Vec<HashSet<Coordinate>>>,
+}
+
+impl MapData<'_> {
+    #[must_use]
+    pub fn with_turn_rule(mut self, turn_rule: TurnRule) -> Self {
+        self.turn_rule = turn_rule;
+        self.visited_sets = OnceLock::new();
+        self
+    }
+
+    pub fn get_nb_guards(&self) -> usize {
+        self.guards.len()
+    }
+
+    /// True if a guard never leaves the map, even without any added obstruction
+    pub fn is_any_guard_looping(&self) -> bool {
+        self.guards
+            .iter()
+            .any(|&guard| MapWalker::new(self, guard, &[]).walk_map())
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - There is no guard at this index
+    pub fn walk(&self, guard_index: usize, added_obstructions: &[Coordinate]) -> Walk {
+        let guard = self.guards[guard_index];
+        let (visited, is_loop) = MapWalker::new(self, guard, added_obstructions).walk_in_order();
+        Walk { visited, is_loop }
+    }
+
+    /// The map with the visited cells drawn as `X` and the added obstructions
+    /// as `O`, walls, guards and special tiles are kept
+    pub fn render(&self, visited: &[Coordinate], added_obstructions: &[Coordinate]) -> String {
+        let mut rows: Vec<Vec<u8>> = (0..self.map.get_height())
+            .map(|y| self.map.row(y).to_vec())
+            .collect();
+        let cells = visited
+            .iter()
+            .filter(|&&position| self.can_be_obstructed(position))
+            .map(|&position| (position, b'X'))
+            .chain(
+                added_obstructions
+                    .iter()
+                    .filter(|&&position| self.is_in_map(position))
+                    .map(|&position| (position, b'O')),
+            )
+            .filter_map(|(position, c)| {
+                let x = usize::try_from(position.x).ok()?;
+                let y = usize::try_from(position.y).ok()?;
+                Some((x, y, c))
+            });
+        for (x, y, c) in cells {
+            rows[y][x] = c;
+        }
+
+        let mut text = String::new();
+        for row in rows {
+            text.push_str(&String::from_utf8_lossy(&row));
+            text.push('\n');
+        }
+        text
+    }
+
+    pub(crate) fn get_guards(&self) -> &[Guard] {
+        &self.guards
+    }
+
+    pub(crate) fn is_in_map(&self, position: Coordinate) -> bool {
+        let width = i32::try_from(self.map.get_width()).unwrap();
+        let height = i32::try_from(self.map.get_height()).unwrap();
+
+        position.x >= 0 && position.x < width && position.y >= 0 && position.y < height
+    }
+
+    /// State of the guard after one move or turn
+    pub(crate) fn get_next_state(
+        &self,
+        mut guard: Guard,
+        added_obstructions: &[Coordinate],
+    ) -> Guard {
+        let facing_position = guard.get_facing_position();
+        match self.get_tile(facing_position, added_obstructions) {
+            Some(Tile::Wall) => guard.turn(self.turn_rule),
+            Some(Tile::OneWay(direction_index)) if direction_index != guard.direction_index => {
+                guard.turn(self.turn_rule);
+            }
+            Some(Tile::Teleporter) => guard.position = self.teleporters[&facing_position],
+            _ => guard.move_forward(),
+        }
+        guard
+    }
+
+    fn get_tile(&self, position: Coordinate, added_obstructions: &[Coordinate]) -> Option<Tile> {
+        if added_obstructions.contains(&position) {
+            return Some(Tile::Wall);
+        }
+
+        self.map.get(position.x, position.y).map(Tile::from)
+    }
+
+    fn get_visited_sets(&self, executor: &Executor) -> &[HashSet<Coordinate>] {
+        self.visited_sets.get_or_init(|| {
+            let _span = debug_span!("initial_walk").entered();
+            self.guards
+                .iter()
+                .map(|&guard| {
+                    let mut initial_map = MapWalker::new(self, guard, &[]).with_executor(executor);
+                    initial_map.walk_map();
+                    initial_map.visited_set
+                })
+                .collect()
+        })
+    }
+
+    /// Cells visited by at least one guard
+    fn get_visited_cells(&self, executor: &Executor) -> HashSet<Coordinate> {
+        self.get_visited_sets(executor)
+            .iter()
+            .flatten()
+            .copied()
+            .collect()
+    }
+
+    /// Every obstruction position that traps a guard, sorted by coordinates then
+    /// guard. An obstruction trapping several guards is listed once per guard.
+    #[instrument(level = "debug", skip_all)]
+    pub fn get_guard_loops(&self, executor: &Executor) -> Vec<GuardLoop> {
+        let candidate_list = self.get_obstruction_candidates(executor);
+
+        executor
+            .map(&candidate_list, |&blocked_position| {
+                self.get_impacted_guards(blocked_position, executor)
+                    .filter_map(|guard_index| {
+                        let guard = self.guards[guard_index];
+                        let mut candidate_map =
+                            MapWalker::new(self, guard, slice::from_ref(&blocked_position))
+                                .with_executor(executor);
+                        candidate_map.find_loop(guard_index)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// Visited open tiles, except where the guards start, sorted by coordinates
+    fn get_obstruction_candidates(&self, executor: &Executor) -> Vec<Coordinate> {
+        let mut candidate_list: Vec<Coordinate> = self
+            .get_visited_cells(executor)
+            .into_iter()
+            .filter(|&position| self.can_be_obstructed(position))
+            .collect();
+        candidate_list.sort_unstable();
+        candidate_list
+    }
+
+    /// Obstruction candidates that are on the walk of this guard
+    pub(crate) fn get_guard_candidates(
+        &self,
+        guard_index: usize,
+        executor: &Executor,
+    ) -> Vec<Coordinate> {
+        let visited_set = &self.get_visited_sets(executor)[guard_index];
+        self.get_obstruction_candidates(executor)
+            .into_iter()
+            .filter(|position| visited_set.contains(position))
+            .collect()
+    }
+
+    /// Only open tiles, where no guard starts, can be obstructed
+    pub(crate) fn can_be_obstructed(&self, position: Coordinate) -> bool {
+        let is_open = matches!(
+            self.map.get(position.x, position.y).map(Tile::from),
+            Some(Tile::Open)
+        );
+        is_open && self.guards.iter().all(|guard| guard.position != position)
+    }
+
+    /// An obstruction can only change the walk of the guards that went there
+    fn get_impacted_guards<'b>(
+        &'b self,
+        blocked_position: Coordinate,
+        executor: &Executor,
+    ) -> impl Iterator<Item = usize> + 'b {
+        self.get_visited_sets(executor)
+            .iter()
+            .enumerate()
+            .filter(move |(_, visited_set)| visited_set.contains(&blocked_position))
+            .map(|(guard_index, _)| guard_index)
+    }
+}
+
+pub(crate) struct MapWalker<'a> {
+    map_data: &'a MapData<'a>,
+    start: Guard,
+    guard: Guard,
+    visited_set: HashSet<Coordinate>,
+    added_obstructions: &'a [Coordinate],
+    states_history: HashSet<Guard>,
+    /// Polled at each step, so that a long walk can be cancelled
+    executor: Option<&'a Executor>,
+}
+
+impl<'a> MapWalker<'a> {
+    pub(crate) fn new(
+        map_data: &'a MapData<'a>,
+        start: Guard,
+        added_obstructions: &'a [Coordinate],
+    ) -> Self {
+        Self {
+            map_data,
+            start,
+            guard: start,
+            visited_set: HashSet::new(),
+            added_obstructions,
+            states_history: HashSet::new(),
+            executor: None,
+        }
+    }
+
+    pub(crate) fn with_executor(mut self, executor: &'a Executor) -> Self {
+        self.executor = Some(executor);
+        self
+    }
+
+    /// Returns true if stuck in a loop, false if went outside of the map
+    #[instrument(level = "trace", skip_all)]
+    pub(crate) fn walk_map(&mut self) -> bool {
+        self.reset_guard();
+
+        while self.is_guard_in_map() {
+            self.check_cancelled();
+            if self.added_obstructions.is_empty() {
+                self.update_visited();
+            }
+            if !self.states_history.insert(self.guard) {
+                return true;
+            }
+
+            self.update_position();
+        }
+        false
+    }
+
+    /// Slower than `walk_map`, as every state is kept in order to describe the loop.
+    /// The loop is attributed to the first added obstruction.
+    #[instrument(level = "trace", skip_all)]
+    pub(crate) fn find_loop(&mut self, guard_index: usize) -> Option<GuardLoop> {
+        self.reset_guard();
+        let mut states_list: Vec<Guard> = Vec::new();
+        let mut states_indexes: HashMap<Guard, usize> = HashMap::new();
+
+        while self.is_guard_in_map() {
+            self.check_cancelled();
+            if let Some(&entry_index) = states_indexes.get(&self.guard) {
+                let cycle = &states_list[entry_index..];
+                return Some(GuardLoop {
+                    obstruction: *self.added_obstructions.first()?,
+                    guard_index,
+                    cycle_length: cycle.len(),
+                    entry_position: self.guard.position,
+                    entry_direction: DIRECTIONS_NAMES[self.guard.direction_index],
+                    cycle_cells: cycle.iter().map(|guard| guard.position).collect(),
+                });
+            }
+            states_indexes.insert(self.guard, states_list.len());
+            states_list.push(self.guard);
+
+            self.update_position();
+        }
+        None
+    }
+
+    /// Cells visited until the guard leaves, in the order of their first visit.
+    /// Returns `None` if the guard gets stuck in a loop.
+    pub(crate) fn walk_to_exit(&mut self) -> Option<Vec<Coordinate>> {
+        let (visited_list, is_loop) = self.walk_in_order();
+        (!is_loop).then_some(visited_list)
+    }
+
+    /// Cells visited in the order of their first visit, until the guard leaves or
+    /// is back in the same state, and whether it got stuck in a loop
+    fn walk_in_order(&mut self) -> (Vec<Coordinate>, bool) {
+        self.reset_guard();
+        let mut visited_list = Vec::new();
+
+        while self.is_guard_in_map() {
+            self.check_cancelled();
+            if self.visited_set.insert(self.guard.position) {
+                visited_list.push(self.guard.position);
+            }
+            if !self.states_history.insert(self.guard) {
+                return (visited_list, true);
+            }
+
+            self.update_position();
+        }
+        (visited_list, false)
+    }
+
+    fn check_cancelled(&self) {
+        if let Some(executor) = self.executor {
+            executor.check_cancelled();
+        }
+    }
+
+    fn reset_guard(&mut self) {
+        self.guard = self.start;
+        self.visited_set.clear();
+        self.states_history.clear();
+    }
+
+    fn is_guard_in_map(&self) -> bool {
+        self.map_data.is_in_map(self.guard.position)
+    }
+
+    fn update_visited(&mut self) {
+        self.visited_set.insert(self.guard.position);
+    }
+
+    fn update_position(&mut self) {
+        self.guard = self
+            .map_data
+            .get_next_state(self.guard, self.added_obstructions);
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Guard {
+    pub(crate) position: Coordinate,
+    direction_index: usize,
+}
+
+impl Guard {
+    fn new(position: Coordinate, direction_index: usize) -> Self {
+        Self {
+            position,
+            direction_index,
+        }
+    }
+
+    fn turn(&mut self, turn_rule: TurnRule) {
+        self.direction_index =
+            (self.direction_index + turn_rule.get_shift()) % DIRECTIONS_LIST.len();
+    }
+
+    fn move_forward(&mut self) {
+        self.position = self.get_facing_position();
+    }
+
+    fn get_facing_position(&self) -> Coordinate {
+        let direction = self.get_direction();
+        add_coordinates(self.position, direction)
+    }
+
+    fn get_direction(&self) -> Coordinate {
+        DIRECTIONS_LIST[self.direction_index]
+    }
+}
+
+pub struct Day06;
+
+impl Day for Day06 {
+    type Parsed<'a> = MapData<'a>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        MapParser::new(input).get_map_data()
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>, executor: &Executor) -> String {
+        executor.start_phase("initial walk");
+        parsed.get_visited_cells(executor).len().to_string()
+    }
+
+    /// Counts the obstructions trapping at least one guard
+    fn part2(parsed: &Self::Parsed<'_>, executor: &Executor) -> String {
+        executor.start_phase("initial walk");
+        let candidate_list = parsed.get_obstruction_candidates(executor);
+
+        executor.start_phase("testing obstructions");
+        // Using multi-threading went from 1.3s to 0.3s on my M1
+        let nb_possible_obstructions = executor.count(&candidate_list, |&blocked_position| {
+            parsed
+                .get_impacted_guards(blocked_position, executor)
+                .any(|guard_index| {
+                    let guard = parsed.guards[guard_index];
+                    let mut candidate_map =
+                        MapWalker::new(parsed, guard, slice::from_ref(&blocked_position))
+                            .with_executor(executor);
+                    candidate_map.walk_map()
+                })
+        });
+
+        nb_possible_obstructions.to_string()
+    }
+}
@@ -0,0 +1,70 @@
+const LOOP_MAP: &[&str] = &[
+    ".#..", //
+    "...#", //
+    "....", //
+    ".^..", //
+    "..#.",
+];
+
+mod to_csv {
+    use super::LOOP_MAP;
+    use aoc2024_common::day::Day;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_day_06::export::to_csv;
+    use aoc2024_day_06::solver::Day06;
+
+    #[test]
+    fn one_loop() {
+        // Given
+        let input = LOOP_MAP.join("\n");
+        let map_data = Day06::parse(&input);
+        let guard_loops = map_data.get_guard_loops(&Executor::sequential());
+
+        // When
+        let result = to_csv(&guard_loops);
+
+        // Then
+        assert_eq!(
+            result,
+            "obstruction_x,obstruction_y,guard,cycle_length,entry_x,entry_y,entry_direction,cycle_cells\n\
+             0,3,0,10,1,3,up,1:1;1:2;1:3;2:1;2:2;2:3\n"
+        );
+    }
+}
+
+mod to_json {
+    use super::LOOP_MAP;
+    use aoc2024_common::day::Day;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_day_06::export::to_json;
+    use aoc2024_day_06::solver::Day06;
+
+    #[test]
+    fn one_loop() {
+        // Given
+        let input = LOOP_MAP.join("\n");
+        let map_data = Day06::parse(&input);
+        let guard_loops = map_data.get_guard_loops(&Executor::sequential());
+
+        // When
+        let result = to_json(&guard_loops);
+
+        // Then
+        assert_eq!(
+            result,
+            "[\n  {\"obstruction\": {\"x\": 0, \"y\": 3}, \"guard\": 0, \"cycle_length\": 10, \
+             \"entry\": {\"position\": {\"x\": 1, \"y\": 3}, \"direction\": \"up\"}, \
+             \"cycle_cells\": [{\"x\": 1, \"y\": 1}, {\"x\": 1, \"y\": 2}, {\"x\": 1, \"y\": 3}, \
+             {\"x\": 2, \"y\": 1}, {\"x\": 2, \"y\": 2}, {\"x\": 2, \"y\": 3}]}\n]\n"
+        );
+    }
+
+    #[test]
+    fn no_loop() {
+        // When
+        let result = to_json(&[]);
+
+        // Then
+        assert_eq!(result, "[]\n");
+    }
+}
@@ -0,0 +1,8 @@
+#![warn(clippy::pedantic)]
+
+pub mod export_test;
+pub mod helpers_test;
+pub mod optimize_test;
+pub mod simulation_test;
+pub mod snapshot_test;
+pub mod solver_test;
@@ -0,0 +1,225 @@
+const LOOP_MAP: &[&str] = &[
+    ".#..", //
+    "...#", //
+    "....", //
+    ".^..", //
+    "..#.",
+];
+
+mod find_placement {
+    use super::LOOP_MAP;
+    use aoc2024_common::day::Day;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_day_06::optimize::{find_placement, Budget, Goal, Placement};
+    use aoc2024_day_06::solver::{Coordinate, Day06};
+    use std::time::Duration;
+
+    #[test]
+    fn most_visited() {
+        // Given
+        let input = LOOP_MAP.join("\n");
+        let map_data = Day06::parse(&input);
+
+        // When
+        let result = find_placement(
+            &map_data,
+            0,
+            Goal::MostVisited,
+            &Executor::sequential(),
+            Budget::unlimited(),
+            |_| {},
+        );
+
+        // Then
+        assert!(result.is_complete);
+        assert_eq!(
+            result.best,
+            Some(Placement {
+                obstruction: Coordinate { x: 2, y: 3 },
+                nb_visited: 6
+            })
+        );
+    }
+
+    #[test]
+    fn fewest_visited() {
+        // Given
+        let input = LOOP_MAP.join("\n");
+        let map_data = Day06::parse(&input);
+
+        // When
+        let result = find_placement(
+            &map_data,
+            0,
+            Goal::FewestVisited,
+            &Executor::sequential(),
+            Budget::unlimited(),
+            |_| {},
+        );
+
+        // Then
+        assert_eq!(
+            result.best,
+            Some(Placement {
+                obstruction: Coordinate { x: 1, y: 2 },
+                nb_visited: 3
+            })
+        );
+    }
+
+    #[test]
+    fn reports_each_improvement() {
+        // Given
+        let input = LOOP_MAP.join("\n");
+        let map_data = Day06::parse(&input);
+        let mut improvements = Vec::new();
+
+        // When
+        find_placement(
+            &map_data,
+            0,
+            Goal::MostVisited,
+            &Executor::new(4),
+            Budget::unlimited(),
+            |placement| improvements.push(placement.nb_visited),
+        );
+
+        // Then
+        assert_eq!(improvements, vec![4, 5, 6]);
+    }
+
+    #[test]
+    fn no_time() {
+        // Given
+        let input = LOOP_MAP.join("\n");
+        let map_data = Day06::parse(&input);
+
+        // When
+        let result = find_placement(
+            &map_data,
+            0,
+            Goal::MostVisited,
+            &Executor::sequential(),
+            Budget::new(Duration::ZERO),
+            |_| {},
+        );
+
+        // Then
+        assert!(!result.is_complete);
+        assert_eq!(result.best, None);
+    }
+}
+
+mod find_longest_cycle {
+    use aoc2024_common::day::Day;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_day_06::optimize::{find_longest_cycle, Budget};
+    use aoc2024_day_06::solver::Day06;
+
+    const PUZZLE_SUBJECT_EXAMPLE: &[&str] = &[
+        "....#.....",
+        ".........#",
+        "..........",
+        "..#.......",
+        ".......#..",
+        "..........",
+        ".#..^.....",
+        "........#.",
+        "#.........",
+        "......#...",
+    ];
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let map_data = Day06::parse(&input);
+        let executor = Executor::sequential();
+        let longest_length = map_data
+            .get_guard_loops(&executor)
+            .iter()
+            .map(|guard_loop| guard_loop.cycle_length)
+            .max();
+
+        // When
+        let result = find_longest_cycle(&map_data, 0, &executor, Budget::unlimited(), |_| {});
+
+        // Then
+        assert!(result.is_complete);
+        assert_eq!(
+            result.best.map(|guard_loop| guard_loop.cycle_length),
+            longest_length
+        );
+    }
+}
+
+mod find_minimal_trap {
+    use super::LOOP_MAP;
+    use aoc2024_common::day::Day;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_day_06::optimize::{find_minimal_trap, Budget};
+    use aoc2024_day_06::solver::{Coordinate, Day06};
+
+    #[test]
+    fn single_obstruction() {
+        // Given
+        let input = LOOP_MAP.join("\n");
+        let map_data = Day06::parse(&input);
+
+        // When
+        let result = find_minimal_trap(
+            &map_data,
+            0,
+            &Executor::sequential(),
+            Budget::unlimited(),
+            |_| {},
+        );
+
+        // Then
+        assert!(result.is_complete);
+        assert_eq!(result.best, Some(vec![Coordinate { x: 0, y: 3 }]));
+    }
+
+    #[test]
+    fn guard_in_an_empty_room() {
+        // Given
+        let input = ["...", ".^.", "..."].join("\n");
+        let map_data = Day06::parse(&input);
+
+        // When
+        let result =
+            find_minimal_trap(&map_data, 0, &Executor::new(4), Budget::unlimited(), |_| {});
+
+        // Then
+        assert!(result.is_complete);
+        assert_eq!(
+            result.best,
+            Some(vec![
+                Coordinate { x: 0, y: 1 },
+                Coordinate { x: 1, y: 0 },
+                Coordinate { x: 1, y: 2 },
+                Coordinate { x: 2, y: 1 },
+            ])
+        );
+    }
+
+    #[test]
+    fn untrappable_guard() {
+        // Given
+        let input = ["^.", ".."].join("\n");
+        let map_data = Day06::parse(&input);
+
+        // When
+        let result = find_minimal_trap(
+            &map_data,
+            0,
+            &Executor::sequential(),
+            Budget::unlimited(),
+            |_| {},
+        );
+
+        // Then
+        assert!(result.is_complete);
+        assert_eq!(result.best, None);
+    }
+}
@@ -0,0 +1,123 @@
+mod run {
+    use aoc2024_common::day::Day;
+    use aoc2024_day_06::simulation::{Collision, CollisionKind, GlobalLoop, Simulation};
+    use aoc2024_day_06::solver::{Coordinate, Day06, TurnRule};
+
+    const MAX_TICKS: usize = 1000;
+
+    #[test]
+    fn single_guard_leaving() {
+        // Given
+        let input = [".#..", "...#", "....", ".^..", "..#."].join("\n");
+        let map_data = Day06::parse(&input);
+
+        // When
+        let report = Simulation::new(&map_data).run(MAX_TICKS);
+
+        // Then
+        assert_eq!(report.exits, vec![Some(11)]);
+        assert_eq!(report.global_loop, None);
+        assert_eq!(report.coverage, 7);
+    }
+
+    #[test]
+    fn guards_meeting_on_a_cell() {
+        // Given
+        let input = ">...<";
+        let map_data = Day06::parse(input);
+
+        // When
+        let report = Simulation::new(&map_data).run(MAX_TICKS);
+
+        // Then
+        assert_eq!(
+            report.collisions,
+            vec![Collision {
+                tick: 2,
+                guards: (0, 1),
+                position: Coordinate { x: 2, y: 0 },
+                kind: CollisionKind::SameCell,
+            }]
+        );
+        assert_eq!(report.exits, vec![Some(5), Some(5)]);
+        assert_eq!(report.contended_cells.len(), 5);
+        assert_eq!(report.coverage, 5);
+    }
+
+    #[test]
+    fn guards_crossing() {
+        // Given
+        let input = ">..<";
+        let map_data = Day06::parse(input);
+
+        // When
+        let report = Simulation::new(&map_data).run(MAX_TICKS);
+
+        // Then
+        assert_eq!(
+            report.collisions,
+            vec![Collision {
+                tick: 2,
+                guards: (0, 1),
+                position: Coordinate { x: 2, y: 0 },
+                kind: CollisionKind::Crossing,
+            }]
+        );
+    }
+
+    #[test]
+    fn global_loop() {
+        // Given
+        let input = ["#.#", "^.^", "#..", "..#"].join("\n");
+        let map_data = Day06::parse(&input).with_turn_rule(TurnRule::Reverse);
+
+        // When
+        let report = Simulation::new(&map_data).run(MAX_TICKS);
+
+        // Then
+        assert_eq!(
+            report.global_loop,
+            Some(GlobalLoop {
+                start: 0,
+                period: 4
+            })
+        );
+        assert_eq!(report.exits, vec![None, None]);
+        assert_eq!(report.coverage, 3);
+        assert!(report.contended_cells.is_empty());
+    }
+
+    #[test]
+    fn global_loop_after_a_guard_left() {
+        // Given
+        let input = ["#.", "^^", "#."].join("\n");
+        let map_data = Day06::parse(&input).with_turn_rule(TurnRule::Reverse);
+
+        // When
+        let report = Simulation::new(&map_data).run(MAX_TICKS);
+
+        // Then
+        assert_eq!(report.exits, vec![None, Some(2)]);
+        assert_eq!(
+            report.global_loop,
+            Some(GlobalLoop {
+                start: 2,
+                period: 2
+            })
+        );
+    }
+
+    #[test]
+    fn tick_limit() {
+        // Given
+        let input = [".#..", "...#", "....", ".^..", "..#."].join("\n");
+        let map_data = Day06::parse(&input);
+
+        // When
+        let report = Simulation::new(&map_data).run(3);
+
+        // Then
+        assert_eq!(report.nb_ticks, 3);
+        assert_eq!(report.exits, vec![None]);
+    }
+}
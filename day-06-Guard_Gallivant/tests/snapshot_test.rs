@@ -0,0 +1,74 @@
+const PUZZLE_SUBJECT_EXAMPLE: &[&str] = &[
+    "....#.....",
+    ".........#",
+    "..........",
+    "..#.......",
+    ".......#..",
+    "..........",
+    ".#..^.....",
+    "........#.",
+    "#.........",
+    "......#...",
+];
+
+mod walk {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::assert_named_snapshot;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_06::solver::Day06;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let map_data = Day06::parse(&input);
+
+        // When
+        let result = map_data.walk(0, &[]);
+
+        // Then
+        assert_named_snapshot!("walk", result);
+    }
+}
+
+mod render {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::assert_named_text_snapshot;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_06::solver::Day06;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let map_data = Day06::parse(&input);
+        let walk = map_data.walk(0, &[]);
+
+        // When
+        let result = map_data.render(&walk.visited, &[]);
+
+        // Then
+        assert_named_text_snapshot!("rendered_walk", result);
+    }
+}
+
+mod get_guard_loops {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::assert_named_snapshot;
+    use aoc2024_common::day::Day;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_day_06::solver::Day06;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let map_data = Day06::parse(&input);
+
+        // When
+        let result = map_data.get_guard_loops(&Executor::sequential());
+
+        // Then
+        assert_named_snapshot!("guard_loops", result);
+    }
+}
@@ -0,0 +1,319 @@
+const PUZZLE_SUBJECT_EXAMPLE: &[&str] = &[
+    "....#.....",
+    ".........#",
+    "..........",
+    "..#.......",
+    ".......#..",
+    "..........",
+    ".#..^.....",
+    "........#.",
+    "#.........",
+    "......#...",
+];
+
+mod part1 {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::day::solve_part1;
+    use aoc2024_day_06::solver::Day06;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // When
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+
+        // When
+        let result = solve_part1::<Day06>(&input);
+
+        // Then
+        assert_eq!(result, "41");
+    }
+
+    #[test]
+    fn start_facing_right() {
+        // Given
+        let input = ["...", ">.#", "..."].join("\n");
+
+        // When
+        let result = solve_part1::<Day06>(&input);
+
+        // Then
+        assert_eq!(result, "3");
+    }
+
+    #[test]
+    fn several_guards() {
+        // Given
+        let input = ["^..", "...", "..<"].join("\n");
+
+        // When
+        let result = solve_part1::<Day06>(&input);
+
+        // Then
+        assert_eq!(result, "4");
+    }
+
+    #[test]
+    fn one_way_tile_in_the_guard_direction() {
+        // Given
+        let input = [".#.", ".N.", ".^."].join("\n");
+
+        // When
+        let result = solve_part1::<Day06>(&input);
+
+        // Then
+        assert_eq!(result, "3");
+    }
+
+    #[test]
+    fn one_way_tile_against_the_guard_direction() {
+        // Given
+        let input = [".#.", ".S.", ".^."].join("\n");
+
+        // When
+        let result = solve_part1::<Day06>(&input);
+
+        // Then
+        assert_eq!(result, "2");
+    }
+
+    #[test]
+    fn teleporter() {
+        // Given
+        let input = [".....", ".1..1", ".^..."].join("\n");
+
+        // When
+        let result = solve_part1::<Day06>(&input);
+
+        // Then
+        assert_eq!(result, "3");
+    }
+
+    #[test]
+    fn guard_looping_without_obstruction() {
+        // Given
+        let input = ["#", "^", "#"].join("\n");
+
+        // When
+        let result = solve_part1::<Day06>(&input);
+
+        // Then
+        assert_eq!(result, "1");
+    }
+}
+
+mod part2 {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::day::solve_part2;
+    use aoc2024_day_06::solver::Day06;
+
+    #[test]
+    fn puzzle_subject_example() {
+        // When
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+
+        // When
+        let result = solve_part2::<Day06>(&input);
+
+        // Then
+        assert_eq!(result, "6");
+    }
+
+    #[test]
+    fn several_guards() {
+        // Given
+        let input = [".#.v", "...#", "....", ".^..", "..#."].join("\n");
+
+        // When
+        let result = solve_part2::<Day06>(&input);
+
+        // Then
+        assert_eq!(result, "1");
+    }
+}
+
+mod parse {
+    use aoc2024_common::day::Day;
+    use aoc2024_day_06::solver::Day06;
+
+    #[test]
+    #[should_panic(expected = "Teleporter 1 needs exactly two tiles")]
+    fn unpaired_teleporter() {
+        // Given
+        let input = ["..1", ".^."].join("\n");
+
+        // When
+        Day06::parse(&input);
+    }
+
+    #[test]
+    #[should_panic(expected = "No guard found in data")]
+    fn no_guard() {
+        // Given
+        let input = ["...", "..."].join("\n");
+
+        // When
+        Day06::parse(&input);
+    }
+}
+
+mod with_turn_rule {
+    use aoc2024_common::day::Day;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_day_06::solver::{Day06, TurnRule};
+
+    const MAP: &[&str] = &[
+        ".#..", //
+        "....", //
+        ".^..",
+    ];
+
+    fn get_visited_count(turn_rule: TurnRule) -> String {
+        let input = MAP.join("\n");
+        let map_data = Day06::parse(&input).with_turn_rule(turn_rule);
+        Day06::part1(&map_data, &Executor::sequential())
+    }
+
+    #[test]
+    fn right() {
+        // When
+        let result = get_visited_count(TurnRule::Right);
+
+        // Then
+        assert_eq!(result, "4");
+    }
+
+    #[test]
+    fn left() {
+        // When
+        let result = get_visited_count(TurnRule::Left);
+
+        // Then
+        assert_eq!(result, "3");
+    }
+
+    #[test]
+    fn reverse() {
+        // When
+        let result = get_visited_count(TurnRule::Reverse);
+
+        // Then
+        assert_eq!(result, "2");
+    }
+}
+
+mod get_guard_loops {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::day::Day;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_day_06::solver::{Coordinate, Day06};
+
+    #[test]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let map_data = Day06::parse(&input);
+
+        // When
+        let guard_loops = map_data.get_guard_loops(&Executor::default());
+
+        // Then
+        let obstructions: Vec<(i32, i32)> = guard_loops
+            .iter()
+            .map(|guard_loop| (guard_loop.obstruction.x, guard_loop.obstruction.y))
+            .collect();
+        assert_eq!(
+            obstructions,
+            vec![(1, 8), (3, 6), (3, 8), (6, 7), (7, 7), (7, 9)]
+        );
+    }
+
+    #[test]
+    fn cycle_of_the_obstruction_next_to_the_guard() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let map_data = Day06::parse(&input);
+
+        // When
+        let guard_loops = map_data.get_guard_loops(&Executor::sequential());
+
+        // Then
+        let guard_loop = &guard_loops[1];
+        assert_eq!(guard_loop.obstruction, Coordinate { x: 3, y: 6 });
+        assert_eq!(guard_loop.entry_position, Coordinate { x: 4, y: 6 });
+        assert_eq!(guard_loop.entry_direction, "up");
+        assert_eq!(guard_loop.cycle_length, 22);
+        assert_eq!(guard_loop.cycle_cells.len(), 18);
+    }
+
+    #[test]
+    fn guard_index_in_reading_order() {
+        // Given
+        let input = [".#.v", "...#", "....", ".^..", "..#."].join("\n");
+        let map_data = Day06::parse(&input);
+
+        // When
+        let guard_loops = map_data.get_guard_loops(&Executor::sequential());
+
+        // Then
+        assert_eq!(guard_loops.len(), 1);
+        assert_eq!(guard_loops[0].obstruction, Coordinate { x: 0, y: 3 });
+        assert_eq!(guard_loops[0].guard_index, 1);
+    }
+}
+
+mod walk {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::day::Day;
+    use aoc2024_day_06::solver::{Coordinate, Day06};
+
+    #[test]
+    fn leaves_the_map() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let map_data = Day06::parse(&input);
+
+        // When
+        let walk = map_data.walk(0, &[]);
+
+        // Then
+        assert!(!walk.is_loop);
+        assert_eq!(walk.visited.len(), 41);
+        assert_eq!(
+            walk.visited[..2],
+            [Coordinate { x: 4, y: 6 }, Coordinate { x: 4, y: 5 }]
+        );
+    }
+
+    #[test]
+    fn stuck_in_a_loop() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join("\n");
+        let map_data = Day06::parse(&input);
+
+        // When
+        let walk = map_data.walk(0, &[Coordinate { x: 3, y: 6 }]);
+
+        // Then
+        assert!(walk.is_loop);
+    }
+}
+
+mod render {
+    use aoc2024_common::day::Day;
+    use aoc2024_day_06::solver::{Coordinate, Day06};
+
+    #[test]
+    fn visited_cells_and_obstructions() {
+        // Given
+        let input = [".#..", "....", ".^..", "...."].join("\n");
+        let map_data = Day06::parse(&input);
+        let obstructions = [Coordinate { x: 3, y: 1 }];
+        let walk = map_data.walk(0, &obstructions);
+
+        // When
+        let result = map_data.render(&walk.visited, &obstructions);
+
+        // Then
+        assert_eq!(result, ".#..\n.XXO\n.^X.\n..X.\n");
+    }
+}
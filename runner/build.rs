@@ -0,0 +1,94 @@
+//! Hashes the sources of each day, with the common crate they build on, the
+//! locked dependencies and the `bigint` feature, so that cached answers are
+//! dropped when a solver changes
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+fn main() -> io::Result<()> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let common = root.join("common");
+    let lock = root.join("Cargo.lock");
+    let mut shared_hash = FNV_OFFSET;
+    if lock.is_file() {
+        shared_hash = hash_file(shared_hash, &lock)?;
+    }
+    println!("cargo:rerun-if-changed={}", lock.display());
+    let bigint = env::var_os("CARGO_FEATURE_BIGINT").is_some();
+    shared_hash = hash_bytes(shared_hash, &[u8::from(bigint)]);
+    println!("cargo:rerun-if-env-changed=CARGO_FEATURE_BIGINT");
+    let mut hashes = Vec::new();
+    for entry in fs::read_dir(&root)? {
+        let path = entry?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let Some(day) = name
+            .strip_prefix("day-")
+            .and_then(|rest| rest.get(..2))
+            .and_then(|day| day.parse::<u8>().ok())
+        else {
+            continue;
+        };
+        let mut hash = shared_hash;
+        for directory in [&common, &path] {
+            hash = hash_file(hash, &directory.join("Cargo.toml"))?;
+            for file in list_files(&directory.join("src"))? {
+                hash = hash_file(hash, &file)?;
+            }
+            println!("cargo:rerun-if-changed={}", directory.display());
+        }
+        hashes.push((day, hash));
+    }
+    hashes.sort_unstable();
+
+    let entries: Vec<String> = hashes
+        .iter()
+        .map(|(day, hash)| format!("    ({day}, {}),\n", format_hash(*hash)))
+        .collect();
+    let output = PathBuf::from(env::var("OUT_DIR").expect("Set by cargo"));
+    fs::write(
+        output.join("solver_hashes.rs"),
+        format!(
+            "const SOLVER_HASHES: &[(u8, u64)] = &[\n{}];\n",
+            entries.concat()
+        ),
+    )
+}
+
+/// Files of a directory and its subdirectories, in a stable order
+fn list_files(directory: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(list_files(&path)?);
+        } else {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// `0x0123_4567_89ab_cdef`, grouped as clippy wants
+fn format_hash(hash: u64) -> String {
+    let digits = format!("{hash:016x}");
+    let groups: Vec<&str> = (0..4)
+        .map(|group| &digits[group * 4..group * 4 + 4])
+        .collect();
+    format!("0x{}", groups.join("_"))
+}
+
+fn hash_file(hash: u64, path: &Path) -> io::Result<u64> {
+    Ok(hash_bytes(hash, &fs::read(path)?))
+}
+
+fn hash_bytes(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    })
+}
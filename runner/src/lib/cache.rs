@@ -0,0 +1,141 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Generated by `build.rs`, the hash of the sources of each day
+include!(concat!(env!("OUT_DIR"), "/solver_hashes.rs"));
+
+/// Relative to the directory the runner is started from
+pub const DEFAULT_DIRECTORY: &str = ".aoc-cache";
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A cached answer is only valid for the same input, solved by the same
+/// sources
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CacheKey {
+    pub day: u8,
+    /// 1 or 2
+    pub part: u8,
+    pub input_hash: u64,
+    pub solver_hash: u64,
+}
+
+impl CacheKey {
+    /// `None` if there is no solver for the day
+    pub fn new(day: u8, part: u8, input: &str) -> Option<Self> {
+        Some(Self {
+            day,
+            part,
+            input_hash: hash_input(input),
+            solver_hash: get_solver_hash(day)?,
+        })
+    }
+
+    fn get_file_name(&self) -> String {
+        format!(
+            "day{:02}-part{}-{:016x}-{:016x}.txt",
+            self.day, self.part, self.input_hash, self.solver_hash
+        )
+    }
+}
+
+/// FNV-1a, stable from one build to the next
+pub fn hash_input(input: &str) -> u64 {
+    input.bytes().fold(FNV_OFFSET, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Hash of the sources of the day and of the common crate, as they were when
+/// the runner was built
+pub fn get_solver_hash(day: u8) -> Option<u64> {
+    SOLVER_HASHES
+        .iter()
+        .find(|(solver_day, _)| *solver_day == day)
+        .map(|(_, hash)| *hash)
+}
+
+/// Answers stored as one file each, named after their key
+pub struct AnswerCache {
+    directory: PathBuf,
+}
+
+impl AnswerCache {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    pub fn get_directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// `None` if the answer is not cached, or cannot be read
+    pub fn get(&self, key: &CacheKey) -> Option<String> {
+        fs::read_to_string(self.directory.join(key.get_file_name())).ok()
+    }
+
+    /// # Errors
+    ///
+    /// Will return an error if the directory or the file cannot be written
+    pub fn insert(&self, key: &CacheKey, answer: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.directory)?;
+        fs::write(self.directory.join(key.get_file_name()), answer)
+    }
+
+    /// Both answers of a day, `None` unless both are cached
+    pub fn get_answers(&self, day: u8, input: &str) -> Option<(String, String)> {
+        let part_1 = self.get(&CacheKey::new(day, 1, input)?)?;
+        let part_2 = self.get(&CacheKey::new(day, 2, input)?)?;
+        Some((part_1, part_2))
+    }
+
+    /// Days without a solver are not cached
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the directory or the files cannot be written
+    pub fn insert_answers(
+        &self,
+        day: u8,
+        input: &str,
+        part_1: &str,
+        part_2: &str,
+    ) -> io::Result<()> {
+        for (part, answer) in [(1, part_1), (2, part_2)] {
+            if let Some(key) = CacheKey::new(day, part, input) {
+                self.insert(&key, answer)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes every cached answer, and the directory once empty, returning
+    /// the number of answers removed
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the directory cannot be read, or an answer
+    /// cannot be removed
+    pub fn clear(&self) -> io::Result<usize> {
+        let entries = match fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(error) => return Err(error),
+        };
+        let mut nb_answers = 0;
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_some_and(|extension| extension == "txt") {
+                fs::remove_file(path)?;
+                nb_answers += 1;
+            }
+        }
+        // Files put there by something else are kept, with the directory
+        let _ = fs::remove_dir(&self.directory);
+        Ok(nb_answers)
+    }
+}
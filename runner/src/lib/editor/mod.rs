@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+
+use aoc2024_common::day::Day;
+use aoc2024_common::exec::Executor;
+use day06::solver::Day06;
+
+pub mod terminal;
+
+const WALL: u8 = b'#';
+const OPEN: u8 = b'.';
+const ORIGIN: u8 = b'^';
+const GUARDS: [u8; 4] = *b"^>v<";
+const ONE_WAY_TILES: [u8; 4] = *b"NESW";
+
+/// Recomputed after each edit
+pub struct Stats {
+    pub part_1: String,
+    pub part_2: String,
+    pub is_looping: bool,
+}
+
+/// Day 6 map being edited, with the answers of the current map.
+///
+/// Only open cells and walls can be toggled, and the origin only moves to open
+/// cells, so the map always stays valid for the solver.
+pub struct Editor {
+    cells: Vec<Vec<u8>>,
+    cursor: (usize, usize),
+    executor: Executor,
+    /// `None` while the map has no guard
+    stats: Option<Stats>,
+    is_modified: bool,
+}
+
+impl Editor {
+    /// Empty map with the origin in the middle
+    ///
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - The map is empty
+    pub fn new(width: usize, height: usize, executor: Executor) -> Self {
+        assert!(width > 0 && height > 0, "The map cannot be empty");
+        let mut cells = vec![vec![OPEN; width]; height];
+        cells[height / 2][width / 2] = ORIGIN;
+        Self::from_cells(cells, executor)
+    }
+
+    /// # Errors
+    ///
+    /// Will return an error, to be shown to the user, if:
+    /// - The map is empty
+    /// - The lines do not all have the same length
+    /// - A character is not one of a day 6 map
+    /// - A teleporter digit is not on exactly two cells
+    pub fn from_text(text: &str, executor: Executor) -> Result<Self, String> {
+        let cells: Vec<Vec<u8>> = text.lines().map(|line| line.as_bytes().to_vec()).collect();
+        if cells.first().is_none_or(Vec::is_empty) {
+            return Err(String::from("The map is empty"));
+        }
+        if let Some(y) = cells.iter().position(|row| row.len() != cells[0].len()) {
+            return Err(format!("Line {} is not as long as the first one", y + 1));
+        }
+
+        let mut teleporters: BTreeMap<u8, usize> = BTreeMap::new();
+        for (y, row) in cells.iter().enumerate() {
+            for (x, &cell) in row.iter().enumerate() {
+                match cell {
+                    WALL | OPEN => {}
+                    b'0'..=b'9' => *teleporters.entry(cell).or_default() += 1,
+                    _ if GUARDS.contains(&cell) || ONE_WAY_TILES.contains(&cell) => {}
+                    _ => return Err(format!("Invalid character {} at {x},{y}", char::from(cell))),
+                }
+            }
+        }
+        if let Some((&digit, &nb_tiles)) = teleporters.iter().find(|(_, &nb_tiles)| nb_tiles != 2) {
+            return Err(format!(
+                "Teleporter {} needs exactly two tiles, not {nb_tiles}",
+                char::from(digit)
+            ));
+        }
+        Ok(Self::from_cells(cells, executor))
+    }
+
+    fn from_cells(cells: Vec<Vec<u8>>, executor: Executor) -> Self {
+        let mut editor = Self {
+            cells,
+            cursor: (0, 0),
+            executor,
+            stats: None,
+            is_modified: false,
+        };
+        editor.update_stats();
+        editor
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for row in &self.cells {
+            text.push_str(&String::from_utf8_lossy(row));
+            text.push('\n');
+        }
+        text
+    }
+
+    pub fn get_rows(&self) -> &[Vec<u8>] {
+        &self.cells
+    }
+
+    pub fn get_cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
+    pub fn get_stats(&self) -> Option<&Stats> {
+        self.stats.as_ref()
+    }
+
+    pub fn is_modified(&self) -> bool {
+        self.is_modified
+    }
+
+    pub fn mark_saved(&mut self) {
+        self.is_modified = false;
+    }
+
+    /// The cursor stays in the map
+    pub fn move_cursor(&mut self, dx: isize, dy: isize) {
+        let (x, y) = self.cursor;
+        let width = self.cells[0].len();
+        let height = self.cells.len();
+        self.cursor = (
+            x.saturating_add_signed(dx).min(width - 1),
+            y.saturating_add_signed(dy).min(height - 1),
+        );
+    }
+
+    /// Returns false, leaving the map untouched, if the cell is neither open nor a wall
+    pub fn toggle_wall(&mut self) -> bool {
+        let (x, y) = self.cursor;
+        let cell = &mut self.cells[y][x];
+        *cell = match *cell {
+            WALL => OPEN,
+            OPEN => WALL,
+            _ => return false,
+        };
+        self.on_edit();
+        true
+    }
+
+    /// Moves the `^` origin under the cursor. Guards facing other directions
+    /// are kept.
+    ///
+    /// Returns false, leaving the map untouched, if the cell is not open
+    pub fn place_origin(&mut self) -> bool {
+        let (x, y) = self.cursor;
+        if self.cells[y][x] != OPEN {
+            return false;
+        }
+        for cell in self.cells.iter_mut().flatten() {
+            if *cell == ORIGIN {
+                *cell = OPEN;
+            }
+        }
+        self.cells[y][x] = ORIGIN;
+        self.on_edit();
+        true
+    }
+
+    fn on_edit(&mut self) {
+        self.is_modified = true;
+        self.update_stats();
+    }
+
+    fn update_stats(&mut self) {
+        let has_guard = self
+            .cells
+            .iter()
+            .flatten()
+            .any(|cell| GUARDS.contains(cell));
+        if !has_guard {
+            self.stats = None;
+            return;
+        }
+
+        let text = self.to_text();
+        let map_data = Day06::parse(&text);
+        self.stats = Some(Stats {
+            part_1: Day06::part1(&map_data, &self.executor),
+            part_2: Day06::part2(&map_data, &self.executor),
+            is_looping: map_data.is_any_guard_looping(),
+        });
+    }
+}
@@ -0,0 +1,159 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+
+use super::Editor;
+
+const HELP: &str = "arrows/hjkl: move  space/#: wall  ^/o: origin  s: save  q: quit";
+/// Lines under the map: stats, help and messages
+const NB_STATUS_LINES: u16 = 3;
+
+/// Runs the editor until the user quits, restoring the terminal even on error
+/// or panic
+///
+/// # Errors
+///
+/// Will return an error if the terminal cannot be driven
+pub fn run(editor: &mut Editor, path: &Path) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    let _guard = TerminalGuard;
+    execute!(stdout, EnterAlternateScreen, Hide)?;
+    run_loop(editor, path, &mut stdout)
+}
+
+/// Leaves the alternate screen and raw mode when dropped, which also happens
+/// while unwinding
+struct TerminalGuard;
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        // Nothing more can be done if the terminal cannot be restored
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn run_loop(editor: &mut Editor, path: &Path, stdout: &mut impl Write) -> io::Result<()> {
+    let mut message = String::new();
+    // Quitting with unsaved changes takes a second `q`
+    let mut is_quit_pending = false;
+    loop {
+        draw(editor, &message, stdout)?;
+        let Event::Key(KeyEvent { code, kind, .. }) = event::read()? else {
+            continue;
+        };
+        if kind != KeyEventKind::Press {
+            continue;
+        }
+
+        message.clear();
+        let was_quit_pending = is_quit_pending;
+        is_quit_pending = false;
+        match code {
+            KeyCode::Left | KeyCode::Char('h') => editor.move_cursor(-1, 0),
+            KeyCode::Right | KeyCode::Char('l') => editor.move_cursor(1, 0),
+            KeyCode::Up | KeyCode::Char('k') => editor.move_cursor(0, -1),
+            KeyCode::Down | KeyCode::Char('j') => editor.move_cursor(0, 1),
+            KeyCode::Char(' ' | '#') => {
+                let is_toggled = editor.toggle_wall();
+                if !is_toggled {
+                    message = String::from("Only open cells and walls can be toggled");
+                }
+            }
+            KeyCode::Char('^' | 'o') => {
+                let is_placed = editor.place_origin();
+                if !is_placed {
+                    message = String::from("The origin can only be placed on an open cell");
+                }
+            }
+            KeyCode::Char('s') => match fs::write(path, editor.to_text()) {
+                Ok(()) => {
+                    editor.mark_saved();
+                    message = format!("Saved to {}", path.display());
+                }
+                // The edits are kept, saving can be tried again
+                Err(error) => message = format!("Could not save to {}: {error}", path.display()),
+            },
+            KeyCode::Char('q') | KeyCode::Esc => {
+                if !editor.is_modified() || was_quit_pending {
+                    return Ok(());
+                }
+                is_quit_pending = true;
+                message = String::from("Unsaved changes, press q again to quit without saving");
+            }
+            _ => {}
+        }
+    }
+}
+
+fn draw(editor: &Editor, message: &str, stdout: &mut impl Write) -> io::Result<()> {
+    let (columns, lines) = terminal::size()?;
+    let view_width = usize::from(columns);
+    let view_height = usize::from(lines.saturating_sub(NB_STATUS_LINES)).max(1);
+    let (cursor_x, cursor_y) = editor.get_cursor();
+    // Scrolls so that the cursor stays visible on maps larger than the terminal
+    let offset_x = cursor_x.saturating_sub(view_width.saturating_sub(1));
+    let offset_y = cursor_y.saturating_sub(view_height.saturating_sub(1));
+
+    queue!(stdout, Clear(ClearType::All))?;
+    for (line, row) in editor
+        .get_rows()
+        .iter()
+        .skip(offset_y)
+        .take(view_height)
+        .enumerate()
+    {
+        queue!(stdout, MoveTo(0, u16::try_from(line).unwrap()))?;
+        for (x, &cell) in row.iter().enumerate().skip(offset_x).take(view_width) {
+            let cell = char::from(cell);
+            if (x, line + offset_y) == (cursor_x, cursor_y) {
+                queue!(
+                    stdout,
+                    SetAttribute(Attribute::Reverse),
+                    Print(cell),
+                    SetAttribute(Attribute::NoReverse)
+                )?;
+            } else {
+                queue!(stdout, Print(cell))?;
+            }
+        }
+    }
+
+    let status_line = u16::try_from(view_height).unwrap();
+    queue!(
+        stdout,
+        MoveTo(0, status_line),
+        Print(get_status(editor)),
+        MoveTo(0, status_line + 1),
+        Print(HELP),
+        MoveTo(0, status_line + 2),
+        Print(message)
+    )?;
+    stdout.flush()
+}
+
+fn get_status(editor: &Editor) -> String {
+    let (x, y) = editor.get_cursor();
+    let modified = if editor.is_modified() {
+        " [modified]"
+    } else {
+        ""
+    };
+    match editor.get_stats() {
+        Some(stats) => {
+            let guard = if stats.is_looping { "loops" } else { "leaves" };
+            format!(
+                "{x},{y}  visited: {}  guard: {guard}  loop obstructions: {}{modified}",
+                stats.part_1, stats.part_2
+            )
+        }
+        None => format!("{x},{y}  no guard on the map{modified}"),
+    }
+}
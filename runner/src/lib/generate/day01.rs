@@ -0,0 +1,30 @@
+use super::GenerateOptions;
+use crate::rng::Rng;
+
+const DEFAULT_SIZE: usize = 1000;
+const MIN_LOCATION: i32 = 10_000;
+const MAX_LOCATION: i32 = 99_999;
+/// Share of right list entries copied from the left list, so that part 2 has similarities to find
+const SHARED_RATIO: f64 = 0.3;
+
+pub fn generate(rng: &mut Rng, options: &GenerateOptions) -> Vec<String> {
+    let size = options.size.unwrap_or(DEFAULT_SIZE);
+    let left_list: Vec<i32> = (0..size)
+        .map(|_| rng.between(MIN_LOCATION, MAX_LOCATION))
+        .collect();
+    let right_list: Vec<i32> = (0..size)
+        .map(|_| {
+            if rng.chance(SHARED_RATIO) {
+                *rng.pick(&left_list)
+            } else {
+                rng.between(MIN_LOCATION, MAX_LOCATION)
+            }
+        })
+        .collect();
+
+    left_list
+        .iter()
+        .zip(right_list)
+        .map(|(left, right)| format!("{left}   {right}"))
+        .collect()
+}
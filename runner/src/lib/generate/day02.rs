@@ -0,0 +1,66 @@
+use super::GenerateOptions;
+use crate::rng::Rng;
+
+const DEFAULT_SIZE: usize = 1000;
+const MIN_LEVELS: i32 = 5;
+const MAX_LEVELS: i32 = 8;
+const MIN_DIFF: i32 = 1;
+const MAX_DIFF: i32 = 3;
+
+fn safe_report(rng: &mut Rng) -> Vec<i32> {
+    let nb_levels = rng.between(MIN_LEVELS, MAX_LEVELS);
+    let sign = if rng.chance(0.5) { 1 } else { -1 };
+    // Far enough from 0 and 100 to never leave that range
+    let mut level = rng.between(25, 75);
+    let mut list = vec![level];
+    for _ in 1..nb_levels {
+        level += sign * rng.between(MIN_DIFF, MAX_DIFF);
+        list.push(level);
+    }
+    list
+}
+
+/// Breaks exactly one pair of a safe report, so it is always unsafe without the dampener
+fn unsafe_report(rng: &mut Rng) -> Vec<i32> {
+    let mut list = safe_report(rng);
+    let sign = (list[1] - list[0]).signum();
+    let index = 1 + rng.below(list.len() - 1);
+    let previous = list[index - 1];
+    list[index] = match rng.below(3) {
+        0 => previous,
+        1 => previous + sign * rng.between(MAX_DIFF + 1, MAX_DIFF + 6),
+        _ => previous - sign * rng.between(MIN_DIFF, MAX_DIFF),
+    };
+    list
+}
+
+pub fn generate(rng: &mut Rng, options: &GenerateOptions) -> Vec<String> {
+    let size = options.size.unwrap_or(DEFAULT_SIZE);
+    let ratio = options.safe_ratio.clamp(0.0, 1.0);
+    // Sizes are small enough for the conversion to be exact
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    let nb_safe = (size as f64 * ratio).round() as usize;
+
+    let mut is_safe_list: Vec<bool> = (0..size).map(|index| index < nb_safe).collect();
+    rng.shuffle(&mut is_safe_list);
+
+    is_safe_list
+        .into_iter()
+        .map(|is_safe| {
+            let report = if is_safe {
+                safe_report(rng)
+            } else {
+                unsafe_report(rng)
+            };
+            report
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect()
+}
@@ -0,0 +1,59 @@
+use super::GenerateOptions;
+use crate::rng::Rng;
+
+const DEFAULT_SIZE: usize = 6;
+const LINE_LENGTH: usize = 3000;
+const NOISE_CHARS: &[char] = &[
+    '!', '@', '#', '$', '%', '^', '&', '*', '(', ')', '[', ']', '{', '}', '<', '>', ',', ';', ':',
+    '\'', '?', '+', '-', '_', ' ', '/', 'w', 'h', 'a', 't', 'f', 'r', 'o', 'm', 's', 'e', 'l', 'c',
+    'y', 'n', 'u',
+];
+
+fn number(rng: &mut Rng, max_digits: i32) -> String {
+    let nb_digits = rng.between(1, max_digits);
+    let max = 10_i32.pow(nb_digits.unsigned_abs()) - 1;
+    rng.between(0, max).to_string()
+}
+
+fn valid_mul(rng: &mut Rng) -> String {
+    format!("mul({},{})", number(rng, 3), number(rng, 3))
+}
+
+/// Looks like a `mul` but must be rejected by the parser
+fn corrupted_mul(rng: &mut Rng) -> String {
+    let left = number(rng, 3);
+    let right = number(rng, 3);
+    match rng.below(6) {
+        0 => format!("mul({left},{right}]"),
+        1 => format!("mul ( {left} , {right} )"),
+        2 => format!("mul({},{right})", rng.between(1000, 99_999)),
+        3 => format!("mul[{left},{right}]"),
+        4 => format!("mul({left}*"),
+        _ => format!("mul({left},{right}!"),
+    }
+}
+
+fn noise(rng: &mut Rng) -> String {
+    let length = rng.below(8) + 1;
+    (0..length).map(|_| *rng.pick(NOISE_CHARS)).collect()
+}
+
+pub fn generate(rng: &mut Rng, options: &GenerateOptions) -> Vec<String> {
+    let size = options.size.unwrap_or(DEFAULT_SIZE);
+    (0..size)
+        .map(|_| {
+            let mut line = String::new();
+            while line.len() < LINE_LENGTH {
+                let fragment = match rng.below(10) {
+                    0..=4 => noise(rng),
+                    5 | 6 => valid_mul(rng),
+                    7 => corrupted_mul(rng),
+                    8 => "do()".to_string(),
+                    _ => "don't()".to_string(),
+                };
+                line.push_str(&fragment);
+            }
+            line
+        })
+        .collect()
+}
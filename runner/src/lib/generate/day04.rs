@@ -0,0 +1,87 @@
+use super::GenerateOptions;
+use crate::rng::Rng;
+
+const DEFAULT_SIZE: usize = 140;
+const LETTERS: [char; 4] = ['X', 'M', 'A', 'S'];
+const SEARCHED_WORD: [char; 4] = ['X', 'M', 'A', 'S'];
+const CROSS_SEQUENCE: [char; 4] = ['M', 'M', 'S', 'S'];
+const CROSS_POSITIONS: [(i32, i32); 4] = [(-1, 1), (1, 1), (1, -1), (-1, -1)];
+const VECTORS: [(i32, i32); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+struct Grid {
+    size: i32,
+    tab: Vec<Vec<char>>,
+}
+
+impl Grid {
+    fn new(rng: &mut Rng, size: usize) -> Self {
+        let tab = (0..size)
+            .map(|_| (0..size).map(|_| *rng.pick(&LETTERS)).collect())
+            .collect();
+        Self {
+            size: i32::try_from(size).unwrap(),
+            tab,
+        }
+    }
+
+    fn set(&mut self, position: (i32, i32), letter: char) {
+        let (x, y) = position;
+        let x = usize::try_from(x).unwrap();
+        let y = usize::try_from(y).unwrap();
+        self.tab[y][x] = letter;
+    }
+
+    fn plant_word(&mut self, rng: &mut Rng) {
+        let (dx, dy) = *rng.pick(&VECTORS);
+        let last = i32::try_from(SEARCHED_WORD.len()).unwrap() - 1;
+        // Keep the whole word inside the grid whatever the direction
+        let x = self.random_start(rng, dx * last);
+        let y = self.random_start(rng, dy * last);
+        for (step, &letter) in (0..).zip(SEARCHED_WORD.iter()) {
+            self.set((x + dx * step, y + dy * step), letter);
+        }
+    }
+
+    fn plant_cross(&mut self, rng: &mut Rng) {
+        let x = rng.between(1, self.size - 2);
+        let y = rng.between(1, self.size - 2);
+        let shift = rng.below(CROSS_SEQUENCE.len());
+        self.set((x, y), 'A');
+        for (index, (dx, dy)) in CROSS_POSITIONS.iter().enumerate() {
+            let letter = CROSS_SEQUENCE[(index + shift) % CROSS_SEQUENCE.len()];
+            self.set((x + dx, y + dy), letter);
+        }
+    }
+
+    fn random_start(&self, rng: &mut Rng, extent: i32) -> i32 {
+        let low = 0.max(-extent);
+        let high = (self.size - 1).min(self.size - 1 - extent);
+        rng.between(low, high)
+    }
+}
+
+pub fn generate(rng: &mut Rng, options: &GenerateOptions) -> Vec<String> {
+    let size = options.size.unwrap_or(DEFAULT_SIZE);
+    let planted = options.planted.unwrap_or(size);
+    let mut grid = Grid::new(rng, size);
+    if size >= SEARCHED_WORD.len() {
+        for _ in 0..planted {
+            grid.plant_word(rng);
+        }
+    }
+    if size >= 3 {
+        for _ in 0..planted {
+            grid.plant_cross(rng);
+        }
+    }
+    grid.tab.iter().map(|row| row.iter().collect()).collect()
+}
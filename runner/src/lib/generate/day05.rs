@@ -0,0 +1,49 @@
+use super::GenerateOptions;
+use crate::rng::Rng;
+
+const DEFAULT_SIZE: usize = 200;
+const NB_PAGES: usize = 49;
+const MIN_UPDATE_LEN: usize = 5;
+const MAX_UPDATE_LEN: usize = 23;
+const ORDERED_RATIO: f64 = 0.5;
+
+fn join_pages(pages: &[i32]) -> String {
+    pages
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Rules are derived from a single hidden order of the pages, and cover every
+/// pair of pages, so that each update has exactly one correct ordering.
+pub fn generate(rng: &mut Rng, options: &GenerateOptions) -> Vec<String> {
+    let size = options.size.unwrap_or(DEFAULT_SIZE);
+    let mut order: Vec<i32> = (10..100).collect();
+    rng.shuffle(&mut order);
+    order.truncate(NB_PAGES);
+
+    let mut rules = Vec::new();
+    for (index, first) in order.iter().enumerate() {
+        for second in &order[index + 1..] {
+            rules.push(format!("{first}|{second}"));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let mut lines = rules;
+    lines.push(String::new());
+    for _ in 0..size {
+        // Updates always have an odd length, to get a middle page
+        let half_len = MIN_UPDATE_LEN / 2 + rng.below((MAX_UPDATE_LEN - MIN_UPDATE_LEN) / 2 + 1);
+        let mut ranks: Vec<usize> = (0..NB_PAGES).collect();
+        rng.shuffle(&mut ranks);
+        ranks.truncate(half_len * 2 + 1);
+        if rng.chance(ORDERED_RATIO) {
+            ranks.sort_unstable();
+        }
+        let update: Vec<i32> = ranks.iter().map(|&rank| order[rank]).collect();
+        lines.push(join_pages(&update));
+    }
+    lines
+}
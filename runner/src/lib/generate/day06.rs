@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+
+use aoc2024_common::day::Day;
+use aoc2024_common::exec::Executor;
+use day06::solver::Day06;
+
+use super::GenerateOptions;
+use crate::rng::Rng;
+
+const DEFAULT_SIZE: usize = 130;
+const WALL_DENSITY: f64 = 0.05;
+const MAX_ATTEMPTS: usize = 2000;
+const DIRECTIONS_LIST: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+struct Map {
+    walls: Vec<Vec<bool>>,
+    origin: (usize, usize),
+}
+
+impl Map {
+    fn new(rng: &mut Rng, size: usize) -> Self {
+        Self {
+            walls: vec![vec![false; size]; size],
+            origin: (rng.below(size), rng.below(size)),
+        }
+    }
+
+    /// Returns false, leaving the map untouched, if the cell is the origin or
+    /// if the change would trap the guard before any obstruction is added
+    fn toggle_wall(&mut self, position: (usize, usize)) -> bool {
+        let (x, y) = position;
+        if position == self.origin {
+            return false;
+        }
+        self.walls[y][x] = !self.walls[y][x];
+        if !self.does_guard_exit() {
+            self.walls[y][x] = !self.walls[y][x];
+            return false;
+        }
+        true
+    }
+
+    /// The solvers only stop once the guard leaves, so such maps are required
+    fn does_guard_exit(&self) -> bool {
+        let size = i32::try_from(self.walls.len()).unwrap();
+        let is_wall = |x: i32, y: i32| {
+            let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) else {
+                return false;
+            };
+            self.walls.get(y).and_then(|row| row.get(x)) == Some(&true)
+        };
+
+        let (x, y) = self.origin;
+        let mut position = (i32::try_from(x).unwrap(), i32::try_from(y).unwrap());
+        let mut direction_index = 0;
+        let mut states_history = HashSet::new();
+        while (0..size).contains(&position.0) && (0..size).contains(&position.1) {
+            if !states_history.insert((position, direction_index)) {
+                return false;
+            }
+            let (dx, dy) = DIRECTIONS_LIST[direction_index];
+            let facing = (position.0 + dx, position.1 + dy);
+            if is_wall(facing.0, facing.1) {
+                direction_index = (direction_index + 1) % DIRECTIONS_LIST.len();
+            } else {
+                position = facing;
+            }
+        }
+        true
+    }
+
+    fn random_position(&self, rng: &mut Rng) -> (usize, usize) {
+        let size = self.walls.len();
+        (rng.below(size), rng.below(size))
+    }
+
+    fn to_lines(&self) -> Vec<String> {
+        self.walls
+            .iter()
+            .enumerate()
+            .map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(x, &is_wall)| match (is_wall, (x, y) == self.origin) {
+                        (_, true) => '^',
+                        (true, false) => '#',
+                        (false, false) => '.',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn count_loops(&self, executor: &Executor) -> usize {
+        let input = self.to_lines().join("\n");
+        let parsed = Day06::parse(&input);
+        Day06::part2(&parsed, executor).parse().unwrap()
+    }
+}
+
+/// Walls are spread at random. With a loop target, random cells are then
+/// toggled, keeping each change unless it moves further from the target,
+/// until the target is met or the attempts run out.
+pub fn generate(rng: &mut Rng, options: &GenerateOptions, executor: &Executor) -> Vec<String> {
+    let size = options.size.unwrap_or(DEFAULT_SIZE);
+    let mut map = Map::new(rng, size);
+    for _ in 0..size * size {
+        if rng.chance(WALL_DENSITY) {
+            let position = map.random_position(rng);
+            if !map.walls[position.1][position.0] {
+                map.toggle_wall(position);
+            }
+        }
+    }
+
+    let Some(target) = options.loops else {
+        return map.to_lines();
+    };
+
+    let mut distance = map.count_loops(executor).abs_diff(target);
+    for _ in 0..MAX_ATTEMPTS {
+        if distance == 0 {
+            break;
+        }
+        let position = map.random_position(rng);
+        if !map.toggle_wall(position) {
+            continue;
+        }
+
+        let candidate_distance = map.count_loops(executor).abs_diff(target);
+        if candidate_distance <= distance {
+            distance = candidate_distance;
+        } else {
+            // Toggling back is always allowed, as it restores a valid map
+            map.toggle_wall(position);
+        }
+    }
+    map.to_lines()
+}
@@ -0,0 +1,74 @@
+use aoc2024_common::exec::Executor;
+
+use crate::rng::Rng;
+use crate::solve::{solve_day, Answers};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+
+/// Days with a generator, one module each
+pub const DAYS: &[u8] = &[1, 2, 3, 4, 5, 6];
+
+pub struct GenerateOptions {
+    pub seed: u64,
+    /// Day dependent: number of lines, reports, updates or grid side, at
+    /// least 1
+    pub size: Option<usize>,
+    /// Day 2 only: share of reports that are safe without the dampener
+    pub safe_ratio: f64,
+    /// Day 4 only: number of `XMAS` words and `X-MAS` crosses to plant
+    pub planted: Option<usize>,
+    /// Day 6 only: wanted number of loop-causing obstructions
+    pub loops: Option<usize>,
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            size: None,
+            safe_ratio: 0.5,
+            planted: None,
+            loops: None,
+        }
+    }
+}
+
+pub struct GeneratedInput {
+    pub lines: Vec<String>,
+    pub part_1: String,
+    pub part_2: String,
+}
+
+/// # Panics
+///
+/// Will panic if:
+/// - There is no generator for the given day
+/// - The size is 0
+/// - The cancel token of the executor is cancelled while solving the input
+pub fn generate(day: u8, options: &GenerateOptions, executor: &Executor) -> GeneratedInput {
+    let mut rng = Rng::new(options.seed);
+    let lines = match day {
+        1 => day01::generate(&mut rng, options),
+        2 => day02::generate(&mut rng, options),
+        3 => day03::generate(&mut rng, options),
+        4 => day04::generate(&mut rng, options),
+        5 => day05::generate(&mut rng, options),
+        6 => day06::generate(&mut rng, options, executor),
+        _ => panic!("No generator for day {day}"),
+    };
+
+    let Answers { part_1, part_2, .. } = solve_day(day, &lines.join("\n"), executor)
+        .unwrap_or_else(|timed_out| {
+            panic!("Part {} of the generated input timed out", timed_out.part)
+        });
+    GeneratedInput {
+        lines,
+        part_1,
+        part_2,
+    }
+}
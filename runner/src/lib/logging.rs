@@ -0,0 +1,59 @@
+use std::io;
+use std::path::Path;
+
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::Layer;
+
+/// Level of the logs for a number of `-v`: warnings only by default, then
+/// the parse and part spans, the main steps of the days, and every walk or
+/// fix of the days
+pub fn get_level(verbosity: u8) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Logs the spans on stderr when they close, with their duration. `RUST_LOG`
+/// takes precedence over the verbosity.
+///
+/// With a Chrome trace path, every span down to the `trace` level is also
+/// written there, as JSON to open in `chrome://tracing` or Perfetto. The file
+/// is complete once the returned guard is dropped.
+///
+/// # Panics
+///
+/// Will panic if:
+/// - A global subscriber is already set
+pub fn init(verbosity: u8, chrome_trace: Option<&Path>) -> Option<FlushGuard> {
+    let filter = EnvFilter::builder()
+        .with_default_directive(get_level(verbosity).into())
+        .from_env_lossy();
+    let fmt_layer = tracing_subscriber::fmt::layer()
+        .with_writer(io::stderr)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_filter(filter);
+
+    let (chrome_layer, guard) = match chrome_trace {
+        Some(path) => {
+            let (layer, guard) = ChromeLayerBuilder::new()
+                .file(path)
+                .include_args(true)
+                .build();
+            (Some(layer.with_filter(LevelFilter::TRACE)), Some(guard))
+        }
+        None => (None, None),
+    };
+
+    tracing_subscriber::registry()
+        .with(fmt_layer)
+        .with(chrome_layer)
+        .init();
+    guard
+}
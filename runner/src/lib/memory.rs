@@ -0,0 +1,21 @@
+use aoc2024_common::alloc::AllocStats;
+#[cfg(feature = "alloc-stats")]
+use aoc2024_common::alloc::CountingAllocator;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator::new();
+
+/// Runs `callback`, with its allocations when the runner is built with the
+/// `alloc-stats` feature
+pub fn measure<R>(callback: impl FnOnce() -> R) -> (R, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let (result, stats) = ALLOCATOR.measure(callback);
+        (result, Some(stats))
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (callback(), None)
+    }
+}
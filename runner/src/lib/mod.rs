@@ -0,0 +1,16 @@
+#![warn(clippy::pedantic)]
+#![allow(clippy::must_use_candidate)]
+
+pub mod cache;
+pub mod editor;
+pub mod generate;
+pub mod logging;
+pub mod memory;
+pub mod progress;
+pub mod repl;
+pub mod rng;
+pub mod scaffold;
+pub mod serve;
+pub mod snapshots;
+pub mod solve;
+pub mod watch;
@@ -0,0 +1,168 @@
+use std::io::{self, IsTerminal, Write};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use aoc2024_common::progress::{Progress, ProgressHook};
+
+const BAR_WIDTH: usize = 30;
+const BAR_INTERVAL: Duration = Duration::from_millis(100);
+const LOG_INTERVAL: Duration = Duration::from_secs(1);
+/// Erases the current line of a terminal
+const CLEAR_LINE: &str = "\r\x1b[2K";
+
+/// A bar redrawn on the same line on a terminal, log lines otherwise, both on
+/// stderr
+pub fn for_stderr() -> Arc<dyn ProgressHook> {
+    if io::stderr().is_terminal() {
+        Arc::new(ProgressBar::new(io::stderr(), BAR_INTERVAL))
+    } else {
+        Arc::new(ProgressLog::new(io::stderr(), LOG_INTERVAL))
+    }
+}
+
+struct State<W> {
+    output: W,
+    phase: String,
+    /// Time of the last drawing or line, `None` if nothing was written yet
+    /// for the phase
+    last_write: Option<Instant>,
+}
+
+impl<W: Write> State<W> {
+    fn new(output: W) -> Self {
+        Self {
+            output,
+            phase: String::new(),
+            last_write: None,
+        }
+    }
+
+    fn is_due(&self, interval: Duration) -> bool {
+        self.last_write
+            .is_none_or(|last_write| last_write.elapsed() >= interval)
+    }
+}
+
+/// `phase [#####.....] 5/10`, redrawn at most once per interval
+pub struct ProgressBar<W> {
+    state: Mutex<State<W>>,
+    interval: Duration,
+}
+
+impl<W: Write + Send> ProgressBar<W> {
+    pub fn new(output: W, interval: Duration) -> Self {
+        Self {
+            state: Mutex::new(State::new(output)),
+            interval,
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - A thread panicked while drawing
+    pub fn into_output(self) -> W {
+        self.state.into_inner().unwrap().output
+    }
+}
+
+impl<W: Write + Send> ProgressHook for ProgressBar<W> {
+    fn start_phase(&self, phase: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.phase = phase.to_string();
+        state.last_write = Some(Instant::now());
+        let _ = write!(state.output, "{CLEAR_LINE}{phase}");
+        let _ = state.output.flush();
+    }
+
+    fn update(&self, progress: Progress) {
+        // Items finishing while the bar is drawn are skipped, the next one
+        // draws it again
+        let Ok(mut state) = self.state.try_lock() else {
+            return;
+        };
+        let is_last = progress.nb_done == progress.nb_items;
+        if !is_last && !state.is_due(self.interval) {
+            return;
+        }
+        state.last_write = Some(Instant::now());
+        let bar = draw_bar(progress);
+        let phase = state.phase.clone();
+        let _ = write!(
+            state.output,
+            "{CLEAR_LINE}{phase} [{bar}] {}/{}",
+            progress.nb_done, progress.nb_items
+        );
+        let _ = state.output.flush();
+    }
+
+    fn finish(&self) {
+        let mut state = self.state.lock().unwrap();
+        let _ = write!(state.output, "{CLEAR_LINE}");
+        let _ = state.output.flush();
+    }
+}
+
+/// A line when a phase starts, then one per interval while it lasts
+pub struct ProgressLog<W> {
+    state: Mutex<State<W>>,
+    interval: Duration,
+    start: Instant,
+}
+
+impl<W: Write + Send> ProgressLog<W> {
+    pub fn new(output: W, interval: Duration) -> Self {
+        Self {
+            state: Mutex::new(State::new(output)),
+            interval,
+            start: Instant::now(),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - A thread panicked while logging
+    pub fn into_output(self) -> W {
+        self.state.into_inner().unwrap().output
+    }
+}
+
+impl<W: Write + Send> ProgressHook for ProgressLog<W> {
+    fn start_phase(&self, phase: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.phase = phase.to_string();
+        state.last_write = Some(Instant::now());
+        let elapsed = self.start.elapsed();
+        let _ = writeln!(state.output, "[{elapsed:.2?}] {phase}");
+    }
+
+    fn update(&self, progress: Progress) {
+        let Ok(mut state) = self.state.try_lock() else {
+            return;
+        };
+        if !state.is_due(self.interval) {
+            return;
+        }
+        state.last_write = Some(Instant::now());
+        let elapsed = self.start.elapsed();
+        let phase = state.phase.clone();
+        let _ = writeln!(
+            state.output,
+            "[{elapsed:.2?}] {phase}: {}/{} items",
+            progress.nb_done, progress.nb_items
+        );
+    }
+}
+
+fn draw_bar(progress: Progress) -> String {
+    let nb_filled = (progress.nb_done * BAR_WIDTH)
+        .checked_div(progress.nb_items)
+        .unwrap_or(0)
+        .min(BAR_WIDTH);
+    format!(
+        "{}{}",
+        "#".repeat(nb_filled),
+        ".".repeat(BAR_WIDTH - nb_filled)
+    )
+}
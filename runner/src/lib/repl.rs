@@ -0,0 +1,298 @@
+use std::io::{self, BufRead, Write};
+
+use aoc2024_common::day::Day;
+use aoc2024_common::exec::Executor;
+use day01::solver::{Day01, LocationLists};
+use day02::solver::{Day02, Report};
+use day03::solver::{Day03, Instruction};
+use day04::solver::{Day04, Grid};
+use day05::solver::{Day05, PrintQueue};
+use day06::solver::{Coordinate, Day06, MapData, Walk};
+
+const PROMPT: &str = "> ";
+
+/// Days with a model
+pub const DAYS: &[u8] = &[1, 2, 3, 4, 5, 6];
+
+/// Parsed input of a day
+enum Model<'a> {
+    Day01(LocationLists),
+    Day02(Vec<Report>),
+    Day03(Vec<Instruction>),
+    Day04(Grid<'a>),
+    Day05(PrintQueue),
+    Day06(MapData<'a>),
+}
+
+/// Queries on the parsed input of a day, which is only parsed once
+pub struct Repl<'a> {
+    model: Model<'a>,
+    executor: Executor,
+    /// Last day 6 walk with its added obstructions, drawn by `show`
+    last_walk: Option<(Walk, Vec<Coordinate>)>,
+}
+
+impl<'a> Repl<'a> {
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - There is no solver for the given day
+    /// - The input cannot be parsed
+    pub fn new(day: u8, input: &'a str, executor: Executor) -> Self {
+        let model = match day {
+            1 => Model::Day01(Day01::parse(input)),
+            2 => Model::Day02(Day02::parse(input)),
+            3 => Model::Day03(Day03::parse(input)),
+            4 => Model::Day04(Day04::parse(input)),
+            5 => Model::Day05(Day05::parse(input)),
+            6 => Model::Day06(Day06::parse(input)),
+            _ => panic!("No REPL for day {day}"),
+        };
+        Self {
+            model,
+            executor,
+            last_walk: None,
+        }
+    }
+
+    /// Runs one command, returning its output or why it failed
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if:
+    /// - The command is unknown for this day
+    /// - Its arguments are missing or invalid
+    pub fn execute(&mut self, line: &str) -> Result<String, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&command, arguments)) = words.split_first() else {
+            return Ok(String::new());
+        };
+        match command {
+            "help" => Ok(self.get_help()),
+            "part1" => Ok(self.solve_part(1)),
+            "part2" => Ok(self.solve_part(2)),
+            _ => match &self.model {
+                Model::Day02(reports) => execute_day02(reports, command, arguments),
+                Model::Day05(print_queue) => execute_day05(print_queue, command, arguments),
+                Model::Day06(map_data) => {
+                    execute_day06(map_data, &mut self.last_walk, command, arguments)
+                }
+                _ => Err(unknown_command(command)),
+            },
+        }
+    }
+
+    fn get_help(&self) -> String {
+        let mut lines = vec![
+            "part1, part2: answers of the parts",
+            "quit, exit: leave the REPL",
+        ];
+        lines.extend_from_slice(match self.model {
+            Model::Day02(_) => &[
+                "report <line>: levels of the report at this line",
+                "is_safe <line>: whether the report at this line is safe",
+            ],
+            Model::Day05(_) => &[
+                "rules-for <page>: pages printed before and after this page",
+                "update <line>: pages of the update at this line, and its order",
+            ],
+            Model::Day06(_) => &[
+                "walk [--guard <index>] [--obstruct <x,y>]...: walk of a guard",
+                "show: map of the last walk",
+            ],
+            _ => &[],
+        });
+        lines.join("\n")
+    }
+
+    fn solve_part(&self, part: u8) -> String {
+        macro_rules! solve {
+            ($day:ty, $parsed:expr) => {
+                if part == 1 {
+                    <$day>::part1($parsed, &self.executor)
+                } else {
+                    <$day>::part2($parsed, &self.executor)
+                }
+            };
+        }
+        match &self.model {
+            Model::Day01(parsed) => solve!(Day01, parsed),
+            Model::Day02(parsed) => solve!(Day02, parsed),
+            Model::Day03(parsed) => solve!(Day03, parsed),
+            Model::Day04(parsed) => solve!(Day04, parsed),
+            Model::Day05(parsed) => solve!(Day05, parsed),
+            Model::Day06(parsed) => solve!(Day06, parsed),
+        }
+    }
+}
+
+/// Reads commands until `quit`, `exit` or the end of the input, printing their
+/// outputs, and their errors on stderr
+///
+/// # Errors
+///
+/// Will return an error if the input cannot be read, or the output written
+pub fn run(repl: &mut Repl<'_>, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    write!(output, "{PROMPT}")?;
+    output.flush()?;
+    for line in input.lines() {
+        let line = line?;
+        if matches!(line.trim(), "quit" | "exit") {
+            break;
+        }
+        match repl.execute(&line) {
+            Ok(text) if text.is_empty() => {}
+            Ok(text) => writeln!(output, "{}", text.trim_end())?,
+            Err(error) => eprintln!("{error}"),
+        }
+        write!(output, "{PROMPT}")?;
+        output.flush()?;
+    }
+    Ok(())
+}
+
+fn execute_day02(reports: &[Report], command: &str, arguments: &[&str]) -> Result<String, String> {
+    let report = || {
+        let line = parse_single_argument::<usize>(command, arguments)?;
+        line.checked_sub(1)
+            .and_then(|index| reports.get(index))
+            .ok_or_else(|| format!("No report at line {line}"))
+    };
+    match command {
+        "report" => {
+            let levels: Vec<String> = report()?.get_levels().iter().map(i32::to_string).collect();
+            Ok(levels.join(" "))
+        }
+        "is_safe" => {
+            let report = report()?;
+            let verdict = if report.is_safe() {
+                "safe"
+            } else if report.is_safe_with_dampener() {
+                "safe with the dampener"
+            } else {
+                "unsafe"
+            };
+            Ok(verdict.to_string())
+        }
+        _ => Err(unknown_command(command)),
+    }
+}
+
+fn execute_day05(
+    print_queue: &PrintQueue,
+    command: &str,
+    arguments: &[&str],
+) -> Result<String, String> {
+    match command {
+        "rules-for" => {
+            let page = parse_single_argument::<i32>(command, arguments)?;
+            let rules = print_queue.get_rules_for(page);
+            Ok(format!(
+                "Before {page}: {}\nAfter {page}: {}",
+                join_pages(&rules.before),
+                join_pages(&rules.after)
+            ))
+        }
+        "update" => {
+            let line = parse_single_argument::<usize>(command, arguments)?;
+            let update = line
+                .checked_sub(1)
+                .and_then(|index| print_queue.get_updates().get(index))
+                .ok_or_else(|| format!("No update {line}"))?;
+            let order = if print_queue.is_correctly_ordered(update) {
+                "correctly ordered"
+            } else {
+                "incorrectly ordered"
+            };
+            Ok(format!("{}: {order}", join_pages(update)))
+        }
+        _ => Err(unknown_command(command)),
+    }
+}
+
+fn execute_day06(
+    map_data: &MapData<'_>,
+    last_walk: &mut Option<(Walk, Vec<Coordinate>)>,
+    command: &str,
+    arguments: &[&str],
+) -> Result<String, String> {
+    match command {
+        "walk" => {
+            let (guard_index, obstructions) = parse_walk_arguments(arguments)?;
+            if guard_index >= map_data.get_nb_guards() {
+                return Err(format!("No guard {guard_index}"));
+            }
+            let walk = map_data.walk(guard_index, &obstructions);
+            let outcome = if walk.is_loop {
+                "is stuck in a loop"
+            } else {
+                "leaves the map"
+            };
+            let description = format!(
+                "Guard {guard_index} {outcome} after visiting {} cells",
+                walk.visited.len()
+            );
+            *last_walk = Some((walk, obstructions));
+            Ok(description)
+        }
+        "show" => Ok(match last_walk {
+            Some((walk, obstructions)) => map_data.render(&walk.visited, obstructions),
+            None => map_data.render(&[], &[]),
+        }),
+        _ => Err(unknown_command(command)),
+    }
+}
+
+/// `[--guard <index>] [--obstruct <x,y>]...`
+fn parse_walk_arguments(arguments: &[&str]) -> Result<(usize, Vec<Coordinate>), String> {
+    let mut guard_index = 0;
+    let mut obstructions = Vec::new();
+    let mut arguments = arguments.iter();
+    while let Some(&option) = arguments.next() {
+        let value = arguments
+            .next()
+            .ok_or_else(|| format!("Missing value after {option}"))?;
+        match option {
+            "--guard" => {
+                guard_index = value
+                    .parse()
+                    .map_err(|_| format!("Invalid guard: {value}"))?;
+            }
+            "--obstruct" => {
+                let position = value
+                    .split_once(',')
+                    .and_then(|(x, y)| {
+                        Some(Coordinate {
+                            x: x.parse().ok()?,
+                            y: y.parse().ok()?,
+                        })
+                    })
+                    .ok_or_else(|| format!("Invalid position: {value}"))?;
+                obstructions.push(position);
+            }
+            _ => return Err(format!("Unknown option: {option}")),
+        }
+    }
+    Ok((guard_index, obstructions))
+}
+
+fn parse_single_argument<T: std::str::FromStr>(
+    command: &str,
+    arguments: &[&str],
+) -> Result<T, String> {
+    let &[argument] = arguments else {
+        return Err(format!("{command} takes one argument"));
+    };
+    argument
+        .parse()
+        .map_err(|_| format!("Invalid argument: {argument}"))
+}
+
+fn join_pages(pages: &[i32]) -> String {
+    let pages: Vec<String> = pages.iter().map(i32::to_string).collect();
+    pages.join(",")
+}
+
+fn unknown_command(command: &str) -> String {
+    format!("Unknown command: {command}, see help")
+}
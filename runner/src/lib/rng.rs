@@ -0,0 +1,70 @@
+/// Small `SplitMix64` generator.
+///
+/// Kept in-tree rather than pulled from a crate so that a given seed keeps
+/// producing the exact same puzzle input across dependency upgrades.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..bound`
+    ///
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - `bound` is 0
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "Cannot pick a number below 0");
+        let bound = bound as u128;
+        let scaled = (u128::from(self.next_u64()) * bound) >> 64;
+        usize::try_from(scaled).unwrap()
+    }
+
+    /// Returns a number in `low..=high`
+    ///
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - `low` is greater than `high`
+    pub fn between(&mut self, low: i32, high: i32) -> i32 {
+        assert!(low <= high, "Empty range {low}..={high}");
+        let span = usize::try_from(i64::from(high) - i64::from(low) + 1).unwrap();
+        let offset = i64::try_from(self.below(span)).unwrap();
+        i32::try_from(i64::from(low) + offset).unwrap()
+    }
+
+    /// Returns true with the given probability, clamped to `0.0..=1.0`
+    pub fn chance(&mut self, probability: f64) -> bool {
+        // 53 bits is the precision of an f64 mantissa
+        #[allow(clippy::cast_precision_loss)]
+        let sample = (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64;
+        sample < probability
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - `items` is empty
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            let other = self.below(index + 1);
+            items.swap(index, other);
+        }
+    }
+}
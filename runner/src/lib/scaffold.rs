@@ -0,0 +1,295 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Replaced by the two-digit day number in the templates
+const NUMBER_TOKEN: &str = "{NN}";
+const SOLVERS_PREFIX: &str = "pub const SOLVERS: &[Solver] = &[";
+
+const MANIFEST_TEMPLATE: &str = r#"[package]
+name = "aoc2024-day-{NN}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc2024-common = { path = "../common" }
+
+[dev-dependencies]
+aoc2024-common = { path = "../common", features = ["dev"] }
+
+[lib]
+path = "src/lib/mod.rs"
+"#;
+
+const MAIN_TEMPLATE: &str = "#![warn(clippy::pedantic)]
+
+use aoc2024_common::day::run_from_args_file;
+use aoc2024_day_{NN}::solver::Day{NN};
+
+fn main() {
+    run_from_args_file::<Day{NN}>();
+}
+";
+
+const LIB_TEMPLATE: &str = "#![warn(clippy::pedantic)]
+#![allow(clippy::must_use_candidate)]
+
+pub mod helpers;
+pub mod solver;
+";
+
+const SOLVER_TEMPLATE: &str = "use aoc2024_common::day::Day;
+use aoc2024_common::exec::Executor;
+
+pub struct Day{NN};
+
+impl Day for Day{NN} {
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.lines().collect()
+    }
+
+    fn part1(_parsed: &Self::Parsed<'_>, _executor: &Executor) -> String {
+        todo!(\"Part 1\")
+    }
+
+    fn part2(_parsed: &Self::Parsed<'_>, _executor: &Executor) -> String {
+        todo!(\"Part 2\")
+    }
+}
+";
+
+const TESTS_TEMPLATE: &str = "#![warn(clippy::pedantic)]
+
+pub mod helpers_test;
+pub mod solver_test;
+";
+
+const SOLVER_TEST_TEMPLATE: &str = "/// Example of the puzzle statement
+const PUZZLE_SUBJECT_EXAMPLE: &[&str] = &[];
+
+mod part1 {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::day::solve_part1;
+    use aoc2024_day_{NN}::solver::Day{NN};
+
+    #[test]
+    #[ignore = \"The example and its answer are still to be filled in\"]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join(\"\\n\");
+
+        // When
+        let result = solve_part1::<Day{NN}>(&input);
+
+        // Then
+        assert_eq!(result, \"\");
+    }
+}
+
+mod part2 {
+    use super::PUZZLE_SUBJECT_EXAMPLE;
+    use aoc2024_common::day::solve_part2;
+    use aoc2024_day_{NN}::solver::Day{NN};
+
+    #[test]
+    #[ignore = \"The example and its answer are still to be filled in\"]
+    fn puzzle_subject_example() {
+        // Given
+        let input = PUZZLE_SUBJECT_EXAMPLE.join(\"\\n\");
+
+        // When
+        let result = solve_part2::<Day{NN}>(&input);
+
+        // Then
+        assert_eq!(result, \"\");
+    }
+}
+";
+
+/// Day to create, with the current layout of the days
+pub struct NewDay {
+    number: u8,
+    title: String,
+}
+
+impl NewDay {
+    /// Spaces in the title become underscores in the directory name
+    ///
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - The day is not between 1 and 25
+    /// - The title is empty, or has characters other than letters, digits,
+    ///   spaces, `-` and `_`
+    pub fn new(number: u8, title: &str) -> Self {
+        assert!((1..=25).contains(&number), "There is no day {number}");
+        let title = title.trim();
+        assert!(
+            !title.is_empty()
+                && title
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '_')),
+            "Invalid title: {title:?}"
+        );
+        Self {
+            number,
+            title: title.replace(' ', "_"),
+        }
+    }
+
+    pub fn get_directory_name(&self) -> String {
+        format!("day-{:02}-{}", self.number, self.title)
+    }
+
+    /// Paths relative to the day directory, with their content
+    pub fn get_files(&self) -> Vec<(PathBuf, String)> {
+        [
+            (".gitignore", "/target\n"),
+            ("Cargo.toml", MANIFEST_TEMPLATE),
+            ("puzzle_input.txt", ""),
+            ("src/main.rs", MAIN_TEMPLATE),
+            ("src/lib/mod.rs", LIB_TEMPLATE),
+            ("src/lib/helpers.rs", "\n"),
+            ("src/lib/solver.rs", SOLVER_TEMPLATE),
+            ("tests/mod.rs", TESTS_TEMPLATE),
+            ("tests/helpers_test.rs", "\n"),
+            ("tests/solver_test.rs", SOLVER_TEST_TEMPLATE),
+        ]
+        .into_iter()
+        .map(|(path, template)| (PathBuf::from(path), self.fill(template)))
+        .collect()
+    }
+
+    /// Adds the day to the dependencies of the runner, in day order
+    pub fn wire_runner_manifest(&self, manifest: &str) -> String {
+        let dependency = format!(
+            "day{:02} = {{ package = \"aoc2024-day-{:02}\", path = \"../{}\" }}",
+            self.number,
+            self.number,
+            self.get_directory_name()
+        );
+        let mut lines: Vec<&str> = manifest.lines().collect();
+        let position = lines
+            .iter()
+            .rposition(|line| get_day_dependency(line).is_some_and(|day| day < self.number))
+            .map(|index| index + 1)
+            .or_else(|| {
+                lines
+                    .iter()
+                    .position(|line| get_day_dependency(line).is_some())
+            })
+            .unwrap_or_else(|| get_dependencies_end(&lines));
+        lines.insert(position, &dependency);
+        join_lines(&lines)
+    }
+
+    /// Adds the day to the solvers, and so to the days accepted by `solve`
+    ///
+    /// # Panics
+    ///
+    /// Will panic if:
+    /// - The source is not the one of the `solve` module
+    pub fn wire_solve(&self, source: &str) -> String {
+        let entry = format!(
+            "    Solver::new::<day{:02}::solver::Day{:02}>({}),",
+            self.number, self.number, self.number
+        );
+        let mut lines: Vec<&str> = source.lines().collect();
+        let start = lines
+            .iter()
+            .position(|line| line.starts_with(SOLVERS_PREFIX))
+            .expect("No SOLVERS table")
+            + 1;
+        let end = start
+            + lines[start..]
+                .iter()
+                .position(|line| *line == "];")
+                .expect("No end to the SOLVERS table");
+        // Kept in order, after the days before this one
+        let position = (start..end)
+            .find(|&index| get_solver_day(lines[index]).is_some_and(|day| day > self.number))
+            .unwrap_or(end);
+        lines.insert(position, &entry);
+        join_lines(&lines)
+    }
+
+    fn fill(&self, template: &str) -> String {
+        template.replace(NUMBER_TOKEN, &format!("{:02}", self.number))
+    }
+}
+
+/// Creates the day in the workspace at `root`, and wires it into the runner.
+/// Returns the directory of the day.
+///
+/// # Errors
+///
+/// Will return an error if:
+/// - A directory already exists for this day
+/// - A file cannot be read or written
+pub fn create_day(root: &Path, new_day: &NewDay) -> io::Result<PathBuf> {
+    let prefix = format!("day-{:02}-", new_day.number);
+    for entry in fs::read_dir(root)? {
+        let name = entry?.file_name();
+        if name.to_string_lossy().starts_with(&prefix) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", name.to_string_lossy()),
+            ));
+        }
+    }
+
+    let directory = root.join(new_day.get_directory_name());
+    for (path, content) in new_day.get_files() {
+        let path = directory.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, content)?;
+    }
+
+    let manifest_path = root.join("runner/Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)?;
+    fs::write(&manifest_path, new_day.wire_runner_manifest(&manifest))?;
+    let solve_path = root.join("runner/src/lib/solve.rs");
+    let source = fs::read_to_string(&solve_path)?;
+    fs::write(&solve_path, new_day.wire_solve(&source))?;
+    Ok(directory)
+}
+
+/// Day of a `dayNN = ...` dependency line
+fn get_day_dependency(line: &str) -> Option<u8> {
+    let (name, _) = line.split_once(" = ")?;
+    name.strip_prefix("day")?.parse().ok()
+}
+
+/// Day of a `Solver::new::<dayNN::solver::DayNN>(N),` line
+fn get_solver_day(line: &str) -> Option<u8> {
+    let (_, day) = line
+        .trim()
+        .strip_prefix("Solver::new::<")?
+        .split_once(">(")?;
+    day.strip_suffix("),")?.parse().ok()
+}
+
+/// Index after the last line of the `[dependencies]` table
+fn get_dependencies_end(lines: &[&str]) -> usize {
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == "[dependencies]")
+        .map_or(lines.len(), |index| index + 1);
+    let mut end = start;
+    while end < lines.len() && !lines[end].trim().is_empty() && !lines[end].starts_with('[') {
+        end += 1;
+    }
+    end
+}
+
+fn join_lines(lines: &[&str]) -> String {
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
@@ -0,0 +1,277 @@
+use std::any::Any;
+use std::fmt::Write;
+use std::io::{self, Read};
+use std::net::SocketAddr;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc2024_common::cancel::{run_cancellable, CancelToken};
+use aoc2024_common::day::Day;
+use aoc2024_common::exec::Executor;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::solve;
+
+const SOLVE_PREFIX: &str = "/solve/";
+
+pub struct ServeOptions {
+    /// Larger inputs are refused, in bytes
+    pub max_input_size: usize,
+    /// A request gets an error after this time, its solver being cancelled
+    pub timeout: Duration,
+}
+
+impl Default for ServeOptions {
+    fn default() -> Self {
+        Self {
+            max_input_size: 1 << 20,
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Answer of a single part, the input being parsed for it
+#[derive(Debug)]
+pub struct PartAnswer {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum SolveError {
+    /// The parser panicked, with this message
+    Parse(String),
+    /// The part panicked, with this message
+    Solve(String),
+    Timeout,
+}
+
+/// Solves one part of a day on another thread, cancelling it after `timeout`.
+///
+/// The solver gets an executor of its own, with `nb_threads` threads as in
+/// `Executor::new`, so that only its own cancel token is cancelled.
+///
+/// # Errors
+///
+/// Will return an error if:
+/// - The parser or the part panics, with the panic message
+/// - There is no answer after `timeout`
+///
+/// # Panics
+///
+/// Will panic if:
+/// - There is no solver for the given day
+/// - The part is neither 1 nor 2
+pub fn solve_day_part(
+    day: u8,
+    part: u8,
+    input: String,
+    nb_threads: usize,
+    timeout: Duration,
+) -> Result<PartAnswer, SolveError> {
+    let solver = solve::get_solver(day).unwrap_or_else(|| panic!("No solver for day {day}"));
+    assert!(matches!(part, 1 | 2), "There is no part {part}");
+
+    let cancel_token = CancelToken::new();
+    let executor = Executor::new(nb_threads).with_cancel_token(cancel_token.clone());
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        // The receiver is gone if the request timed out
+        let _ = sender.send(solver.solve_part(part, &input, &executor));
+    });
+    receiver.recv_timeout(timeout).unwrap_or_else(|_| {
+        cancel_token.cancel();
+        Err(SolveError::Timeout)
+    })
+}
+
+pub(crate) fn solve_part<D: Day>(
+    part: u8,
+    input: &str,
+    executor: &Executor,
+) -> Result<PartAnswer, SolveError> {
+    let now = Instant::now();
+    let parsed = panic::catch_unwind(AssertUnwindSafe(|| {
+        run_cancellable(|| {
+            executor.check_cancelled();
+            D::parse(input)
+        })
+    }))
+    .map_err(|payload| SolveError::Parse(get_panic_message(payload.as_ref())))?
+    .map_err(|_| SolveError::Timeout)?;
+    let parse_time = now.elapsed();
+
+    let now = Instant::now();
+    let answer = panic::catch_unwind(AssertUnwindSafe(|| {
+        run_cancellable(|| {
+            if part == 1 {
+                D::part1(&parsed, executor)
+            } else {
+                D::part2(&parsed, executor)
+            }
+        })
+    }))
+    .map_err(|payload| SolveError::Solve(get_panic_message(payload.as_ref())))?
+    .map_err(|_| SolveError::Timeout)?;
+    let solve_time = now.elapsed();
+
+    Ok(PartAnswer {
+        answer,
+        parse_time,
+        solve_time,
+    })
+}
+
+fn get_panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("Unknown panic")
+    }
+}
+
+/// HTTP server answering `POST /solve/{day}/{part}`, the body being the raw
+/// puzzle input.
+///
+/// Answers are JSON objects, with `answer`, `parse_time_ms` and
+/// `solve_time_ms` on success, and `error` otherwise. Requests are handled one
+/// at a time.
+pub struct SolveServer {
+    server: Server,
+    options: ServeOptions,
+    /// Of the executor created for each request
+    nb_threads: usize,
+}
+
+impl SolveServer {
+    /// Port `0` picks any free port, see `get_address`. Each request is solved
+    /// with `nb_threads` threads, as in `Executor::new`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the address cannot be listened to
+    pub fn bind(address: &str, options: ServeOptions, nb_threads: usize) -> io::Result<Self> {
+        let server = Server::http(address).map_err(io::Error::other)?;
+        Ok(Self {
+            server,
+            options,
+            nb_threads,
+        })
+    }
+
+    pub fn get_address(&self) -> Option<SocketAddr> {
+        self.server.server_addr().to_ip()
+    }
+
+    /// Handles requests until the server is dropped
+    pub fn run(&self) {
+        for request in self.server.incoming_requests() {
+            self.handle(request);
+        }
+    }
+
+    fn handle(&self, mut request: Request) {
+        let (status, body) = self.respond_to(&mut request);
+        let header = Header::from_bytes("Content-Type", "application/json").unwrap();
+        let response = Response::from_string(body + "\n")
+            .with_status_code(status)
+            .with_header(header);
+        // The client may already be gone, nothing to do about it
+        let _ = request.respond(response);
+    }
+
+    /// Status code and JSON body
+    fn respond_to(&self, request: &mut Request) -> (u16, String) {
+        let Some((day, part)) = parse_solve_path(request.url()) else {
+            return (404, error_json(&format!("Not found: {}", request.url())));
+        };
+        if *request.method() != Method::Post {
+            return (405, error_json("Only POST is allowed"));
+        }
+        if solve::get_solver(day).is_none() {
+            return (404, error_json(&format!("No solver for day {day}")));
+        }
+        if !matches!(part, 1 | 2) {
+            return (404, error_json(&format!("There is no part {part}")));
+        }
+
+        let max_input_size = self.options.max_input_size;
+        let too_large = || {
+            let message = format!("The input is larger than {max_input_size} bytes");
+            (413, error_json(&message))
+        };
+        if request
+            .body_length()
+            .is_some_and(|length| length > max_input_size)
+        {
+            return too_large();
+        }
+        let mut body = Vec::new();
+        let limit = u64::try_from(max_input_size)
+            .unwrap_or(u64::MAX)
+            .saturating_add(1);
+        if let Err(error) = request.as_reader().take(limit).read_to_end(&mut body) {
+            return (400, error_json(&format!("Cannot read the input: {error}")));
+        }
+        if body.len() > max_input_size {
+            return too_large();
+        }
+        let Ok(input) = String::from_utf8(body) else {
+            return (400, error_json("The input is not UTF-8"));
+        };
+
+        match solve_day_part(day, part, input, self.nb_threads, self.options.timeout) {
+            Ok(part_answer) => (200, answer_json(day, part, &part_answer)),
+            Err(SolveError::Parse(message)) => {
+                (422, error_json(&format!("Invalid input: {message}")))
+            }
+            Err(SolveError::Solve(message)) => {
+                (500, error_json(&format!("Solver failed: {message}")))
+            }
+            Err(SolveError::Timeout) => {
+                let message = format!("No answer after {:.2?}", self.options.timeout);
+                (504, error_json(&message))
+            }
+        }
+    }
+}
+
+/// `/solve/5/1` gives `(5, 1)`
+fn parse_solve_path(url: &str) -> Option<(u8, u8)> {
+    let path = url.split('?').next()?;
+    let (day, part) = path.strip_prefix(SOLVE_PREFIX)?.split_once('/')?;
+    Some((day.parse().ok()?, part.parse().ok()?))
+}
+
+fn answer_json(day: u8, part: u8, part_answer: &PartAnswer) -> String {
+    format!(
+        "{{\"day\": {day}, \"part\": {part}, \"answer\": {}, \"parse_time_ms\": {:.3}, \"solve_time_ms\": {:.3}}}",
+        to_json_string(&part_answer.answer),
+        part_answer.parse_time.as_secs_f64() * 1000.0,
+        part_answer.solve_time.as_secs_f64() * 1000.0
+    )
+}
+
+fn error_json(message: &str) -> String {
+    format!("{{\"error\": {}}}", to_json_string(message))
+}
+
+fn to_json_string(text: &str) -> String {
+    let mut output = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            c if c.is_control() => write!(output, "\\u{:04x}", u32::from(c)).unwrap(),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
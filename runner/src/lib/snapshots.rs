@@ -0,0 +1,96 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use similar::TextDiff;
+
+const NEW_EXTENSION: &str = "snap.new";
+/// Only used to find the failing assertion, not kept once accepted
+const ASSERTION_LINE_PREFIX: &str = "assertion_line: ";
+
+/// Snapshot written next to the reviewed one by a failing `insta` assertion
+#[derive(PartialEq, Eq, Debug)]
+pub struct PendingSnapshot {
+    /// The reviewed `.snap`, which may not exist yet
+    pub path: PathBuf,
+}
+
+impl PendingSnapshot {
+    pub fn get_new_path(&self) -> PathBuf {
+        self.path.with_extension(NEW_EXTENSION)
+    }
+
+    /// Unified diff from the reviewed snapshot, everything being added for a
+    /// new one
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the pending snapshot cannot be read
+    pub fn get_diff(&self) -> io::Result<String> {
+        let old = fs::read_to_string(&self.path).unwrap_or_default();
+        let new = self.read_new()?;
+        let old_name = self.path.display().to_string();
+        let new_name = self.get_new_path().display().to_string();
+        Ok(TextDiff::from_lines(&old, &new)
+            .unified_diff()
+            .header(&old_name, &new_name)
+            .to_string())
+    }
+
+    /// Replaces the reviewed snapshot
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the pending snapshot cannot be read or removed,
+    /// or the reviewed one cannot be written
+    pub fn accept(&self) -> io::Result<()> {
+        fs::write(&self.path, self.read_new()?)?;
+        fs::remove_file(self.get_new_path())
+    }
+
+    /// # Errors
+    ///
+    /// Will return an error if the pending snapshot cannot be removed
+    pub fn reject(&self) -> io::Result<()> {
+        fs::remove_file(self.get_new_path())
+    }
+
+    fn read_new(&self) -> io::Result<String> {
+        let content = fs::read_to_string(self.get_new_path())?;
+        Ok(content
+            .split_inclusive('\n')
+            .filter(|line| !line.starts_with(ASSERTION_LINE_PREFIX))
+            .collect())
+    }
+}
+
+/// Every pending snapshot under `root`, sorted by path. Hidden directories
+/// and `target` are skipped.
+///
+/// # Errors
+///
+/// Will return an error if a directory cannot be read
+pub fn find_pending(root: &Path) -> io::Result<Vec<PendingSnapshot>> {
+    let mut pending = Vec::new();
+    for entry in fs::read_dir(root)? {
+        let path = entry?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if path.is_dir() {
+            if !name.starts_with('.') && name != "target" {
+                pending.extend(find_pending(&path)?);
+            }
+        } else if path.extension().is_some_and(|extension| extension == "new") {
+            let snapshot_path = path.with_extension("");
+            if snapshot_path
+                .extension()
+                .is_some_and(|extension| extension == "snap")
+            {
+                pending.push(PendingSnapshot {
+                    path: snapshot_path,
+                });
+            }
+        }
+    }
+    pending.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(pending)
+}
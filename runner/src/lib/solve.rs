@@ -0,0 +1,169 @@
+use std::time::{Duration, Instant};
+
+use aoc2024_common::alloc::AllocStats;
+use aoc2024_common::cancel::{run_cancellable, Cancelled};
+use aoc2024_common::day::Day;
+use aoc2024_common::exec::Executor;
+use aoc2024_common::progress::Progress;
+use tracing::info_span;
+
+use crate::memory;
+use crate::serve::{self, PartAnswer, SolveError};
+
+/// Every day with a solver, in order, updated by `new-day`
+pub const SOLVERS: &[Solver] = &[
+    Solver::new::<day01::solver::Day01>(1),
+    Solver::new::<day02::solver::Day02>(2),
+    Solver::new::<day03::solver::Day03>(3),
+    Solver::new::<day04::solver::Day04>(4),
+    Solver::new::<day05::solver::Day05>(5),
+    Solver::new::<day06::solver::Day06>(6),
+];
+
+/// The entry points of a day, for the commands choosing it at runtime
+pub struct Solver {
+    pub day: u8,
+    solve: fn(&str, &Executor) -> Result<Answers, TimedOut>,
+    solve_part: fn(u8, &str, &Executor) -> Result<PartAnswer, SolveError>,
+}
+
+impl Solver {
+    const fn new<D: Day>(day: u8) -> Self {
+        Self {
+            day,
+            solve: solve::<D>,
+            solve_part: serve::solve_part::<D>,
+        }
+    }
+
+    /// Both parts, see `solve`
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the cancel token of the executor is cancelled
+    /// before the parts are solved
+    pub fn solve(&self, input: &str, executor: &Executor) -> Result<Answers, TimedOut> {
+        (self.solve)(input, executor)
+    }
+
+    pub(crate) fn solve_part(
+        &self,
+        part: u8,
+        input: &str,
+        executor: &Executor,
+    ) -> Result<PartAnswer, SolveError> {
+        (self.solve_part)(part, input, executor)
+    }
+}
+
+pub fn get_solver(day: u8) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|solver| solver.day == day)
+}
+
+pub fn get_days() -> Vec<u8> {
+    SOLVERS.iter().map(|solver| solver.day).collect()
+}
+
+pub struct Answers {
+    pub part_1: String,
+    pub part_2: String,
+    pub parse_time: Duration,
+    pub part_1_time: Duration,
+    pub part_2_time: Duration,
+    /// Allocations of each phase, when the runner is built with the
+    /// `alloc-stats` feature
+    pub parse_alloc: Option<AllocStats>,
+    pub part_1_alloc: Option<AllocStats>,
+    pub part_2_alloc: Option<AllocStats>,
+}
+
+/// Part stopped by the cancel token of the executor
+#[derive(Debug)]
+pub struct TimedOut {
+    /// 1 or 2, or 0 if parsing was stopped
+    pub part: u8,
+    /// `None` if parsing was stopped
+    pub parse_time: Option<Duration>,
+    /// Answer and time of part 1, if part 2 was the one stopped
+    pub part_1: Option<(String, Duration)>,
+    /// Items of the current phase of the stopped part
+    pub progress: Progress,
+}
+
+/// Parses the input once, then solves both parts from it
+///
+/// # Errors
+///
+/// Will return an error if the cancel token of the executor is cancelled
+/// before the parts are solved
+pub fn solve<D: Day>(input: &str, executor: &Executor) -> Result<Answers, TimedOut> {
+    executor.start_phase("parsing");
+    let now = Instant::now();
+    let (parsed, parse_alloc) = info_span!("parse").in_scope(|| {
+        memory::measure(|| {
+            run_cancellable(|| {
+                executor.check_cancelled();
+                D::parse(input)
+            })
+        })
+    });
+    let parsed = parsed.map_err(|_| TimedOut {
+        part: 0,
+        parse_time: None,
+        part_1: None,
+        progress: executor.get_progress(),
+    })?;
+    let parse_time = now.elapsed();
+
+    let timed_out = |part, part_1| TimedOut {
+        part,
+        parse_time: Some(parse_time),
+        part_1,
+        progress: executor.get_progress(),
+    };
+    let (part_1, part_1_time, part_1_alloc) =
+        solve_part(executor, 1, || D::part1(&parsed, executor)).map_err(|_| timed_out(1, None))?;
+    let (part_2, part_2_time, part_2_alloc) =
+        solve_part(executor, 2, || D::part2(&parsed, executor))
+            .map_err(|_| timed_out(2, Some((part_1.clone(), part_1_time))))?;
+
+    Ok(Answers {
+        part_1,
+        part_2,
+        parse_time,
+        part_1_time,
+        part_2_time,
+        parse_alloc,
+        part_1_alloc,
+        part_2_alloc,
+    })
+}
+
+/// Solvers may name their own phases, the part is the default one
+fn solve_part(
+    executor: &Executor,
+    part: u8,
+    solve: impl FnOnce() -> String,
+) -> Result<(String, Duration, Option<AllocStats>), Cancelled> {
+    let _span = info_span!("part", part).entered();
+    executor.start_phase(&format!("part {part}"));
+    let now = Instant::now();
+    let (answer, alloc) = memory::measure(|| run_cancellable(solve));
+    Ok((answer?, now.elapsed(), alloc))
+}
+
+/// # Errors
+///
+/// Will return an error if the cancel token of the executor is cancelled
+/// before the parts are solved
+///
+/// # Panics
+///
+/// Will panic if:
+/// - There is no solver for the given day
+pub fn solve_day(day: u8, input: &str, executor: &Executor) -> Result<Answers, TimedOut> {
+    let _span = info_span!("day", day).entered();
+    get_solver(day)
+        .unwrap_or_else(|| panic!("No solver for day {day}"))
+        .solve(input, executor)
+}
@@ -0,0 +1,259 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify::{EventKind, RecursiveMode, Watcher};
+
+/// Changes closer than this are handled together, editors often write a file
+/// several times when saving it
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(200);
+
+/// Answers and timings printed by the `solve` command
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SolveOutput {
+    pub parse_time: Duration,
+    pub part_1: String,
+    pub part_1_time: Duration,
+    pub part_2: String,
+    pub part_2_time: Duration,
+}
+
+#[derive(Default, PartialEq, Eq, Debug)]
+pub struct TestCounts {
+    pub passed: usize,
+    pub failed: usize,
+    pub ignored: usize,
+}
+
+/// Reads durations as printed with `{:.2?}`, such as `1.56ms`
+pub fn parse_duration(text: &str) -> Option<Duration> {
+    let (value, unit_in_seconds) = [("ns", 1e-9), ("µs", 1e-6), ("ms", 1e-3), ("s", 1.0)]
+        .into_iter()
+        .find_map(|(suffix, unit)| Some((text.strip_suffix(suffix)?, unit)))?;
+    let value: f64 = value.parse().ok()?;
+    Duration::try_from_secs_f64(value * unit_in_seconds).ok()
+}
+
+/// `None` if the output is not the one of a successful `solve`. Allocation
+/// counts, printed between brackets, are ignored.
+pub fn parse_solve_output(output: &str) -> Option<SolveOutput> {
+    let mut parse_time = None;
+    let mut part_1 = None;
+    let mut part_2 = None;
+    for line in output.lines() {
+        let line = line.split_once(" [").map_or(line, |(line, _)| line);
+        if let Some(time) = line.strip_prefix("Parsed in: ") {
+            parse_time = parse_duration(time);
+        } else if let Some(answer) = line.strip_prefix("Part 1: ") {
+            part_1 = parse_answer(answer);
+        } else if let Some(answer) = line.strip_prefix("Part 2: ") {
+            part_2 = parse_answer(answer);
+        }
+    }
+
+    let (part_1, part_1_time) = part_1?;
+    let (part_2, part_2_time) = part_2?;
+    Some(SolveOutput {
+        parse_time: parse_time?,
+        part_1,
+        part_1_time,
+        part_2,
+        part_2_time,
+    })
+}
+
+/// Sums the `test result:` lines of a `cargo test` output
+pub fn parse_test_counts(output: &str) -> TestCounts {
+    let mut counts = TestCounts::default();
+    for line in output.lines() {
+        let Some(result) = line.strip_prefix("test result: ") else {
+            continue;
+        };
+        for part in result.split([';', '.']) {
+            let mut words = part.split_whitespace();
+            let (Some(count), Some(kind)) = (words.next(), words.next()) else {
+                continue;
+            };
+            let Ok(count) = count.parse::<usize>() else {
+                continue;
+            };
+            match kind {
+                "passed" => counts.passed += count,
+                "failed" => counts.failed += count,
+                "ignored" => counts.ignored += count,
+                _ => {}
+            }
+        }
+    }
+    counts
+}
+
+/// One line for the parsing and for each part, comparing the answers and
+/// timings with those of the previous run
+pub fn describe_changes(previous: Option<&SolveOutput>, current: &SolveOutput) -> Vec<String> {
+    let parse_line = format!(
+        "Parsed in: {}",
+        describe_time(previous.map(|output| output.parse_time), current.parse_time)
+    );
+    let part_lines = [
+        (
+            "Part 1",
+            previous.map(|output| (output.part_1.as_str(), output.part_1_time)),
+            (current.part_1.as_str(), current.part_1_time),
+        ),
+        (
+            "Part 2",
+            previous.map(|output| (output.part_2.as_str(), output.part_2_time)),
+            (current.part_2.as_str(), current.part_2_time),
+        ),
+    ]
+    .into_iter()
+    .map(|(name, previous, (answer, time))| {
+        let time = describe_time(previous.map(|(_, time)| time), time);
+        match previous {
+            Some((previous_answer, _)) if previous_answer == answer => {
+                format!("{name}: {answer}, unchanged ({time})")
+            }
+            Some((previous_answer, _)) => format!("{name}: {previous_answer} -> {answer} ({time})"),
+            None => format!("{name}: {answer} ({time})"),
+        }
+    });
+
+    std::iter::once(parse_line).chain(part_lines).collect()
+}
+
+/// Re-runs the example tests and the solver of a day each time its sources,
+/// tests or input change, until interrupted.
+///
+/// The solver is run through `cargo`, so that the changes are compiled, and
+/// without the answer cache, so that its timings are compared.
+///
+/// # Errors
+///
+/// Will return an error if:
+/// - There is no directory for the day in `root`
+/// - The files cannot be watched
+/// - `cargo` cannot be started
+pub fn run(root: &Path, day: u8, input: Option<PathBuf>) -> io::Result<()> {
+    let day_directory = find_day_directory(root, day)?;
+    let input = input.unwrap_or_else(|| day_directory.join("puzzle_input.txt"));
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(io::Error::other)?;
+    for directory in ["src", "tests"] {
+        watcher
+            .watch(&day_directory.join(directory), RecursiveMode::Recursive)
+            .map_err(io::Error::other)?;
+    }
+    watcher
+        .watch(&input, RecursiveMode::NonRecursive)
+        .map_err(io::Error::other)?;
+
+    println!("Watching {}", day_directory.display());
+    let mut previous = run_once(root, day, &input, None)?;
+    loop {
+        let Ok(event) = receiver.recv() else {
+            return Ok(());
+        };
+        if !is_change(&event) {
+            continue;
+        }
+        while receiver.recv_timeout(DEBOUNCE_DELAY).is_ok() {}
+        previous = run_once(root, day, &input, previous)?;
+    }
+}
+
+fn is_change(event: &notify::Result<notify::Event>) -> bool {
+    event.as_ref().is_ok_and(|event| {
+        matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        )
+    })
+}
+
+/// Returns the new answers, or the previous ones if the solver failed, so that
+/// the next run is compared with the last one that worked
+fn run_once(
+    root: &Path,
+    day: u8,
+    input: &Path,
+    previous: Option<SolveOutput>,
+) -> io::Result<Option<SolveOutput>> {
+    println!();
+    let package = format!("aoc2024-day-{day:02}");
+    let tests = Command::new("cargo")
+        .args(["test", "-q", "-p", &package])
+        .current_dir(root)
+        .output()?;
+    let tests_output = String::from_utf8_lossy(&tests.stdout);
+    let counts = parse_test_counts(&tests_output);
+    if tests.status.success() {
+        println!(
+            "Tests: {} passed, {} ignored",
+            counts.passed, counts.ignored
+        );
+    } else {
+        print!("{tests_output}");
+        eprint!("{}", String::from_utf8_lossy(&tests.stderr));
+        println!("Tests: {} passed, {} failed", counts.passed, counts.failed);
+    }
+
+    let solve = Command::new("cargo")
+        .args([
+            "run",
+            "-q",
+            "--release",
+            "-p",
+            "aoc2024-runner",
+            "--",
+            "solve",
+            "--no-cache",
+        ])
+        .arg(day.to_string())
+        .arg(input)
+        .current_dir(root)
+        .output()?;
+    let Some(current) = parse_solve_output(&String::from_utf8_lossy(&solve.stdout)) else {
+        eprint!("{}", String::from_utf8_lossy(&solve.stderr));
+        println!("Solver failed");
+        return Ok(previous);
+    };
+    for line in describe_changes(previous.as_ref(), &current) {
+        println!("{line}");
+    }
+    Ok(Some(current))
+}
+
+fn find_day_directory(root: &Path, day: u8) -> io::Result<PathBuf> {
+    let prefix = format!("day-{day:02}-");
+    for entry in fs::read_dir(root)? {
+        let entry = entry?;
+        if entry.file_name().to_string_lossy().starts_with(&prefix) {
+            return Ok(entry.path());
+        }
+    }
+    Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("No directory for day {day}"),
+    ))
+}
+
+/// `2583 (1.56ms)`
+fn parse_answer(text: &str) -> Option<(String, Duration)> {
+    let (answer, time) = text.rsplit_once(" (")?;
+    let time = parse_duration(time.strip_suffix(')')?)?;
+    Some((answer.to_string(), time))
+}
+
+/// The time, and its change from the previous one in percent
+fn describe_time(previous: Option<Duration>, current: Duration) -> String {
+    let Some(previous) = previous.filter(|previous| !previous.is_zero()) else {
+        return format!("{current:.2?}");
+    };
+    let change = (current.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+    format!("{current:.2?}, {change:+.0}%")
+}
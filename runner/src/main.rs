@@ -0,0 +1,822 @@
+#![warn(clippy::pedantic)]
+
+use std::fs;
+use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::Duration;
+
+use aoc2024_common::alloc::AllocStats;
+use aoc2024_common::cancel::CancelToken;
+use aoc2024_common::day::Day;
+use aoc2024_common::exec::Executor;
+use aoc2024_common::input::Input;
+use aoc2024_runner::cache::{self, AnswerCache};
+use aoc2024_runner::editor::{self, Editor};
+use aoc2024_runner::generate::{self, generate, GenerateOptions, GeneratedInput};
+use aoc2024_runner::logging;
+use aoc2024_runner::progress;
+use aoc2024_runner::repl::{self, Repl};
+use aoc2024_runner::scaffold::{create_day, NewDay};
+use aoc2024_runner::serve::{ServeOptions, SolveServer};
+use aoc2024_runner::snapshots;
+use aoc2024_runner::solve::{self, solve_day, Answers};
+use aoc2024_runner::watch;
+use clap::{Parser, Subcommand, ValueEnum};
+use day03::lint::lint;
+use day04::solver::{Edges, Grid};
+use day05::checker::OnlineChecker;
+use day05::graph::{PageOrder, RuleGraph};
+use day05::solver::Day05;
+use day06::export::{to_csv, to_json};
+use day06::optimize::{
+    find_longest_cycle, find_minimal_trap, find_placement, Budget, Goal, Placement, SearchResult,
+};
+use day06::simulation::{CollisionKind, GlobalLoop, Simulation, SimulationReport};
+use day06::solver::{Coordinate, Day06, GuardLoop, MapData, TurnRule};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2024 tooling")]
+struct Cli {
+    /// Number of threads used by the solvers, 0 for one per core, 1 for a
+    /// deterministic single-threaded run
+    #[arg(long, global = true, default_value_t = 0)]
+    threads: usize,
+    /// Log the spans of the solvers on stderr, repeat for more details.
+    /// `RUST_LOG` takes precedence.
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    /// Write every span as a Chrome trace JSON there
+    #[arg(long, global = true)]
+    chrome_trace: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve both parts of a day, parsing the input only once
+    Solve {
+        #[arg(value_parser = parse_solved_day)]
+        day: u8,
+        input: PathBuf,
+        /// Stop the solver after this many seconds, reporting how far it got
+        #[arg(long)]
+        timeout: Option<f64>,
+        /// Do not report the progress of the solver on stderr
+        #[arg(long)]
+        no_progress: bool,
+        /// Solve even if the answers are cached, without caching them
+        #[arg(long)]
+        no_cache: bool,
+    },
+    /// Manage the answers cached by `solve`, keyed by input and solver sources
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+    /// Generate a random puzzle input, and print its expected answers
+    Generate {
+        #[arg(value_parser = parse_generated_day)]
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Number of lines, reports or updates, or grid side, depending on the day
+        #[arg(long, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        size: Option<usize>,
+        /// Day 2: share of reports that are safe without the dampener
+        #[arg(long, default_value_t = 0.5)]
+        safe_ratio: f64,
+        /// Day 4: number of words and crosses to plant
+        #[arg(long)]
+        planted: Option<usize>,
+        /// Day 6: wanted number of loop-causing obstructions
+        #[arg(long)]
+        loops: Option<usize>,
+        /// Write the input there instead of stdout
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Re-run the tests and the solver of a day each time its files change,
+    /// comparing the answers and timings with the previous run
+    Watch {
+        #[arg(value_parser = parse_solved_day)]
+        day: u8,
+        /// The `puzzle_input.txt` of the day by default
+        input: Option<PathBuf>,
+        /// Root of the workspace
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+    /// Answer `POST /solve/{day}/{part}` requests over HTTP, the body being
+    /// the puzzle input
+    Serve {
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        address: String,
+        /// Larger inputs are refused, in bytes
+        #[arg(long, default_value_t = 1 << 20)]
+        max_input_size: usize,
+        /// Seconds before a request gets an error instead of its answer
+        #[arg(long, default_value_t = 10.0)]
+        timeout: f64,
+    },
+    /// Parse the input of a day once, then query it interactively, see `help`
+    Repl {
+        #[arg(value_parser = parse_repl_day)]
+        day: u8,
+        input: PathBuf,
+    },
+    /// Show the snapshots written by failing snapshot tests, as diffs from the
+    /// reviewed ones, to accept or reject them
+    Snapshots {
+        /// Replace the reviewed snapshots with the pending ones
+        #[arg(long, conflicts_with = "reject")]
+        accept: bool,
+        /// Remove the pending snapshots
+        #[arg(long)]
+        reject: bool,
+        /// Root of the workspace
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+    /// Create a new day from the template, wired into the runner
+    NewDay {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(required = true)]
+        title: Vec<String>,
+        /// Root of the workspace
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+    /// Day 3: list the instructions rejected by the parser, and those disabled
+    /// by a `don't()`
+    Lint { input: PathBuf },
+    /// Day 4: count words in every direction in a single scan, optionally
+    /// across wrapping edges or through stacked layers
+    Search {
+        input: PathBuf,
+        /// Searched words, each counted as many times as it is given
+        #[arg(
+            long,
+            value_delimiter = ',',
+            default_value = "XMAS",
+            value_parser = clap::builder::NonEmptyStringValueParser::new()
+        )]
+        words: Vec<String>,
+        /// Words continue across the opposite edge, as on a torus
+        #[arg(long)]
+        wrap: bool,
+        /// The input is layers separated by blank lines, searched in 3D
+        #[arg(long)]
+        layers: bool,
+    },
+    /// Day 5: order all the pages from the rules, or show the cycles preventing it
+    Order {
+        input: PathBuf,
+        /// Also write the rules as a Graphviz graph there, cycles in red
+        #[arg(long)]
+        dot: Option<PathBuf>,
+    },
+    /// Day 5: check updates read from stdin while rules come and go. Lines are
+    /// `X|Y` to add a rule, `-X|Y` to remove it, or an update.
+    Check,
+    /// Day 6: list every loop-causing obstruction, with the loop it produces
+    Loops {
+        input: PathBuf,
+        #[arg(long, value_enum, default_value_t = Format::Csv)]
+        format: Format,
+        /// What the guards do when facing a wall
+        #[arg(long, value_enum, default_value_t = Turn::Right)]
+        turn: Turn,
+        /// Write the list there instead of stdout
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Day 6: move all the guards at once, reporting collisions, loops and coverage
+    Simulate {
+        input: PathBuf,
+        /// What the guards do when facing a wall
+        #[arg(long, value_enum, default_value_t = Turn::Right)]
+        turn: Turn,
+        #[arg(long, default_value_t = 1_000_000)]
+        max_ticks: usize,
+    },
+    /// Day 6: search the obstructions that trap a guard, or change its walk the most
+    Optimize {
+        input: PathBuf,
+        #[arg(value_enum)]
+        query: Query,
+        /// Index of the guard, in reading order
+        #[arg(long, default_value_t = 0)]
+        guard: usize,
+        /// What the guards do when facing a wall
+        #[arg(long, value_enum, default_value_t = Turn::Right)]
+        turn: Turn,
+        /// Stop after this many seconds, with the best answer so far
+        #[arg(long)]
+        time_budget: Option<f64>,
+    },
+    /// Day 6: edit a map in the terminal, with its answers updated live
+    Edit {
+        /// Created on save if it does not exist
+        path: PathBuf,
+        /// Size of a new map
+        #[arg(long, default_value_t = 10)]
+        width: usize,
+        #[arg(long, default_value_t = 10)]
+        height: usize,
+    },
+}
+
+#[derive(Subcommand)]
+enum CacheAction {
+    /// Remove every cached answer
+    Clear,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Query {
+    /// Smallest set of obstructions keeping the guard in the map
+    Trap,
+    /// Obstruction giving the most visited cells before the guard leaves
+    MostVisited,
+    /// Obstruction giving the fewest visited cells before the guard leaves
+    FewestVisited,
+    /// Obstruction giving the longest loop
+    LongestCycle,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Csv,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Turn {
+    Right,
+    Left,
+    Reverse,
+}
+
+impl From<Turn> for TurnRule {
+    fn from(value: Turn) -> Self {
+        match value {
+            Turn::Right => TurnRule::Right,
+            Turn::Left => TurnRule::Left,
+            Turn::Reverse => TurnRule::Reverse,
+        }
+    }
+}
+
+/// Day among `days`, the ones supported by the command
+fn parse_day(text: &str, days: &[u8]) -> Result<u8, String> {
+    let day = text
+        .parse()
+        .map_err(|_| format!("`{text}` is not a day number"))?;
+    if days.contains(&day) {
+        Ok(day)
+    } else {
+        let days: Vec<String> = days.iter().map(u8::to_string).collect();
+        Err(format!("Day {day} is not among {}", days.join(", ")))
+    }
+}
+
+fn parse_solved_day(text: &str) -> Result<u8, String> {
+    parse_day(text, &solve::get_days())
+}
+
+fn parse_generated_day(text: &str) -> Result<u8, String> {
+    parse_day(text, generate::DAYS)
+}
+
+fn parse_repl_day(text: &str) -> Result<u8, String> {
+    parse_day(text, repl::DAYS)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let _trace_guard = logging::init(cli.verbose, cli.chrome_trace.as_deref());
+    let executor = Executor::new(cli.threads);
+    match cli.command {
+        Command::Solve {
+            day,
+            input,
+            timeout,
+            no_progress,
+            no_cache,
+        } => return print_answers(day, &input, executor, timeout, !no_progress, !no_cache),
+        Command::Cache {
+            action: CacheAction::Clear,
+        } => clear_cache(&AnswerCache::new(cache::DEFAULT_DIRECTORY)),
+        Command::Generate {
+            day,
+            seed,
+            size,
+            safe_ratio,
+            planted,
+            loops,
+            output,
+        } => {
+            let options = GenerateOptions {
+                seed,
+                size,
+                safe_ratio,
+                planted,
+                loops,
+            };
+            write_generated(&generate(day, &options, &executor), output);
+        }
+        Command::Watch { day, input, root } => {
+            watch::run(&root, day, input).unwrap_or_else(|error| panic!("Cannot watch: {error}"));
+        }
+        Command::Serve {
+            address,
+            max_input_size,
+            timeout,
+        } => run_server(&address, max_input_size, timeout, cli.threads),
+        Command::Repl { day, input } => run_repl(day, &input, executor),
+        Command::Snapshots {
+            accept,
+            reject,
+            root,
+        } => review_snapshots(&root, accept, reject),
+        Command::NewDay { day, title, root } => create_new_day(day, &title.join(" "), &root),
+        Command::Lint { input } => {
+            let input = open_input(&input);
+            print_lint(input.as_str());
+        }
+        Command::Search {
+            input,
+            words,
+            wrap,
+            layers,
+        } => search_words(&input, &words, wrap, layers, &executor),
+        Command::Order { input, dot } => order_pages(&input, dot),
+        Command::Check => run_checker(io::stdin().lock()),
+        Command::Loops {
+            input,
+            format,
+            turn,
+            output,
+        } => {
+            let input = open_input(&input);
+            let map_data = Day06::parse(input.as_str()).with_turn_rule(turn.into());
+            export_guard_loops(&map_data, format, output, &executor);
+        }
+        Command::Simulate {
+            input,
+            turn,
+            max_ticks,
+        } => {
+            let input = open_input(&input);
+            let map_data = Day06::parse(input.as_str()).with_turn_rule(turn.into());
+            print_simulation_report(&Simulation::new(&map_data).run(max_ticks));
+        }
+        Command::Optimize {
+            input,
+            query,
+            guard,
+            turn,
+            time_budget,
+        } => {
+            let input = open_input(&input);
+            let map_data = Day06::parse(input.as_str()).with_turn_rule(turn.into());
+            assert!(guard < map_data.get_nb_guards(), "No guard {guard}");
+            let budget = time_budget.map_or(Budget::unlimited(), |seconds| {
+                Budget::new(Duration::from_secs_f64(seconds))
+            });
+            run_optimization(&map_data, query, guard, &executor, budget);
+        }
+        Command::Edit {
+            path,
+            width,
+            height,
+        } => return run_editor(&path, width, height, executor),
+    }
+    ExitCode::SUCCESS
+}
+
+fn run_editor(path: &Path, width: usize, height: usize, executor: Executor) -> ExitCode {
+    let mut editor = if path.exists() {
+        let text =
+            fs::read_to_string(path).unwrap_or_else(|_| panic!("Cannot read {}", path.display()));
+        match Editor::from_text(&text, executor) {
+            Ok(editor) => editor,
+            Err(error) => {
+                eprintln!("Cannot edit {}: {error}", path.display());
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        Editor::new(width, height, executor)
+    };
+    editor::terminal::run(&mut editor, path)
+        .unwrap_or_else(|error| panic!("Terminal error: {error}"));
+    ExitCode::SUCCESS
+}
+
+fn print_answers(
+    day: u8,
+    input: &Path,
+    executor: Executor,
+    timeout: Option<f64>,
+    show_progress: bool,
+    use_cache: bool,
+) -> ExitCode {
+    let input = open_input(input);
+    let cache = use_cache.then(|| AnswerCache::new(cache::DEFAULT_DIRECTORY));
+    if let Some((part_1, part_2)) = cache
+        .as_ref()
+        .and_then(|cache| cache.get_answers(day, input.as_str()))
+    {
+        println!("Part 1: {part_1} (cached)");
+        println!("Part 2: {part_2} (cached)");
+        return ExitCode::SUCCESS;
+    }
+    let progress_hook = show_progress.then(progress::for_stderr);
+    let executor = match &progress_hook {
+        Some(progress_hook) => executor.with_progress_hook(Arc::clone(progress_hook)),
+        None => executor,
+    };
+    let executor = match timeout {
+        Some(seconds) => {
+            let cancel_token = CancelToken::new();
+            cancel_token.cancel_after(Duration::from_secs_f64(seconds));
+            executor.with_cancel_token(cancel_token)
+        }
+        None => executor,
+    };
+    let result = solve_day(day, input.as_str(), &executor);
+    if let Some(progress_hook) = progress_hook {
+        progress_hook.finish();
+    }
+    match result {
+        Ok(Answers {
+            part_1,
+            part_2,
+            parse_time,
+            part_1_time,
+            part_2_time,
+            parse_alloc,
+            part_1_alloc,
+            part_2_alloc,
+        }) => {
+            let describe = |alloc: Option<AllocStats>| {
+                alloc.map_or(String::new(), |alloc| format!(" [{alloc}]"))
+            };
+            println!("Parsed in: {parse_time:.2?}{}", describe(parse_alloc));
+            println!(
+                "Part 1: {part_1} ({part_1_time:.2?}){}",
+                describe(part_1_alloc)
+            );
+            println!(
+                "Part 2: {part_2} ({part_2_time:.2?}){}",
+                describe(part_2_alloc)
+            );
+            if let Some(cache) = &cache {
+                if let Err(error) = cache.insert_answers(day, input.as_str(), &part_1, &part_2) {
+                    eprintln!("Cannot cache the answers: {error}");
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Err(timed_out) => {
+            if let Some(parse_time) = timed_out.parse_time {
+                println!("Parsed in: {parse_time:.2?}");
+            }
+            if let Some((part_1, part_1_time)) = &timed_out.part_1 {
+                println!("Part 1: {part_1} ({part_1_time:.2?})");
+            }
+            let progress = timed_out.progress;
+            let stage = match timed_out.part {
+                0 => "Parsing".to_string(),
+                part => format!("Part {part}"),
+            };
+            eprintln!(
+                "{stage}: timed out, {} of {} items done",
+                progress.nb_done, progress.nb_items
+            );
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn clear_cache(cache: &AnswerCache) {
+    let nb_answers = cache.clear().unwrap_or_else(|error| {
+        panic!("Cannot clear {}: {error}", cache.get_directory().display())
+    });
+    println!("Removed {nb_answers} cached answers");
+}
+
+fn run_server(address: &str, max_input_size: usize, timeout: f64, nb_threads: usize) {
+    let options = ServeOptions {
+        max_input_size,
+        timeout: Duration::from_secs_f64(timeout),
+    };
+    let server = SolveServer::bind(address, options, nb_threads)
+        .unwrap_or_else(|error| panic!("Cannot listen to {address}: {error}"));
+    if let Some(address) = server.get_address() {
+        println!("Listening on http://{address}");
+    }
+    server.run();
+}
+
+fn run_repl(day: u8, input: &Path, executor: Executor) {
+    let input = open_input(input);
+    let mut repl = Repl::new(day, input.as_str(), executor);
+    repl::run(&mut repl, io::stdin().lock(), io::stdout())
+        .unwrap_or_else(|error| panic!("REPL error: {error}"));
+}
+
+fn review_snapshots(root: &Path, accept: bool, reject: bool) {
+    let pending = snapshots::find_pending(root)
+        .unwrap_or_else(|error| panic!("Cannot search {}: {error}", root.display()));
+    for snapshot in &pending {
+        let path = snapshot.path.display();
+        let result = if accept {
+            snapshot.accept().map(|()| println!("Accepted {path}"))
+        } else if reject {
+            snapshot.reject().map(|()| println!("Rejected {path}"))
+        } else {
+            snapshot.get_diff().map(|diff| print!("{diff}"))
+        };
+        result.unwrap_or_else(|error| panic!("Cannot review {path}: {error}"));
+    }
+    if !accept && !reject {
+        println!(
+            "{} pending snapshots, use --accept or --reject once reviewed",
+            pending.len()
+        );
+    }
+}
+
+fn open_input(path: &Path) -> Input {
+    Input::open(path).unwrap_or_else(|_| panic!("file not found {}", path.display()))
+}
+
+fn create_new_day(day: u8, title: &str, root: &Path) {
+    let new_day = NewDay::new(day, title);
+    let directory = create_day(root, &new_day)
+        .unwrap_or_else(|error| panic!("Cannot create day {day}: {error}"));
+    println!("Created {}", directory.display());
+}
+
+fn write_generated(generated: &GeneratedInput, output: Option<PathBuf>) {
+    let mut input = generated.lines.join("\n");
+    input.push('\n');
+
+    if let Some(path) = output {
+        fs::write(&path, input).unwrap_or_else(|_| panic!("Cannot write to {}", path.display()));
+        println!("Part 1: {}", generated.part_1);
+        println!("Part 2: {}", generated.part_2);
+    } else {
+        // Answers go to stderr so that the input can be piped
+        print!("{input}");
+        eprintln!("Part 1: {}", generated.part_1);
+        eprintln!("Part 2: {}", generated.part_2);
+    }
+}
+
+fn print_lint(input: &str) {
+    let result = lint(input);
+    println!("Near misses: {}", result.near_misses.len());
+    for near_miss in &result.near_misses {
+        let reasons: Vec<String> = near_miss.reasons.iter().map(ToString::to_string).collect();
+        println!(
+            "  offset {}: {}: {}",
+            near_miss.offset,
+            near_miss.text,
+            reasons.join(", ")
+        );
+    }
+    println!("Disabled muls: {}", result.suppressed_muls.len());
+    for suppressed_mul in &result.suppressed_muls {
+        println!(
+            "  offset {}: mul({},{}), disabled by the don't() at offset {}",
+            suppressed_mul.offset,
+            suppressed_mul.left,
+            suppressed_mul.right,
+            suppressed_mul.dont_offset
+        );
+    }
+}
+
+fn order_pages(input: &Path, dot: Option<PathBuf>) {
+    let input = open_input(input);
+    let graph = Day05::parse(input.as_str()).get_rule_graph();
+    print_page_order(&graph);
+    if let Some(path) = dot {
+        fs::write(&path, graph.to_dot())
+            .unwrap_or_else(|_| panic!("Cannot write to {}", path.display()));
+    }
+}
+
+fn print_page_order(graph: &RuleGraph) {
+    let join = |pages: &[i32]| {
+        let pages: Vec<String> = pages.iter().map(i32::to_string).collect();
+        pages.join(",")
+    };
+    match graph.get_page_order() {
+        PageOrder::Linear(pages) => println!("Page order: {}", join(&pages)),
+        PageOrder::Cyclic(condensation) => {
+            println!("No page order, the rules have cycles");
+            println!("Components, in order:");
+            for (index, component) in condensation.components.iter().enumerate() {
+                let cycle = if component.is_cyclic { " (cycle)" } else { "" };
+                println!("  {index}: {}{cycle}", join(&component.pages));
+            }
+            let edges: Vec<String> = condensation
+                .edges
+                .iter()
+                .map(|(from, to)| format!("{from}->{to}"))
+                .collect();
+            if edges.is_empty() {
+                println!("Component links: none");
+            } else {
+                println!("Component links: {}", edges.join(" "));
+            }
+        }
+    }
+}
+
+fn search_words(input: &Path, words: &[String], wrap: bool, layers: bool, executor: &Executor) {
+    let input = open_input(input);
+    let grid = if layers {
+        Grid::new_layered(input.as_str())
+    } else {
+        Grid::new(input.as_str())
+    };
+    let edges = if wrap {
+        Edges::Wrapping
+    } else {
+        Edges::Bounded
+    };
+    let words: Vec<&[u8]> = words.iter().map(String::as_bytes).collect();
+    let nb_words = grid.with_edges(edges).count_words(&words, executor);
+    println!("Words found: {nb_words}");
+}
+
+/// Prints the verdict of each new update, and the verdicts changed by each rule
+fn run_checker(lines: impl BufRead) {
+    let parse_rule = |rule: &str| {
+        let (before, after) = rule.split_once('|')?;
+        Some((before.trim().parse().ok()?, after.trim().parse().ok()?))
+    };
+    let mut checker = OnlineChecker::new();
+    for line in lines.lines() {
+        let line = line.unwrap_or_else(|error| panic!("Cannot read stdin: {error}"));
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let changes = if line.contains('|') {
+            let (rule, is_removed) = match line.strip_prefix('-') {
+                Some(rule) => (rule, true),
+                None => (line, false),
+            };
+            let Some((before, after)) = parse_rule(rule) else {
+                eprintln!("Invalid rule: {line}");
+                continue;
+            };
+            if is_removed {
+                checker.remove_rule(before, after)
+            } else {
+                checker.add_rule(before, after)
+            }
+        } else {
+            let Ok(pages) = line.split(',').map(|page| page.trim().parse()).collect() else {
+                eprintln!("Invalid update: {line}");
+                continue;
+            };
+            let (update_index, is_valid) = checker.add_update(pages);
+            let verdict = if is_valid { "valid" } else { "invalid" };
+            println!("Update {update_index}: {verdict}");
+            continue;
+        };
+
+        for change in changes {
+            let verdict = if change.is_valid { "valid" } else { "invalid" };
+            println!("Update {}: now {verdict}", change.update_index);
+        }
+    }
+}
+
+fn export_guard_loops(
+    map_data: &MapData<'_>,
+    format: Format,
+    output: Option<PathBuf>,
+    executor: &Executor,
+) {
+    let guard_loops = map_data.get_guard_loops(executor);
+    let exported = match format {
+        Format::Csv => to_csv(&guard_loops),
+        Format::Json => to_json(&guard_loops),
+    };
+
+    if let Some(path) = output {
+        fs::write(&path, exported).unwrap_or_else(|_| panic!("Cannot write to {}", path.display()));
+        println!("{} loop-causing obstructions", guard_loops.len());
+    } else {
+        print!("{exported}");
+    }
+}
+
+fn print_simulation_report(report: &SimulationReport) {
+    println!("Ticks: {}", report.nb_ticks);
+    for (guard_index, exit) in report.exits.iter().enumerate() {
+        match exit {
+            Some(tick) => println!("Guard {guard_index}: left at tick {tick}"),
+            None => println!("Guard {guard_index}: still in the map"),
+        }
+    }
+    match &report.global_loop {
+        Some(GlobalLoop { start, period }) => {
+            println!("Global loop: from tick {start}, every {period} ticks");
+        }
+        None => println!("Global loop: none"),
+    }
+    println!("Collisions: {}", report.collisions.len());
+    for collision in &report.collisions {
+        let kind = match collision.kind {
+            CollisionKind::SameCell => "same cell",
+            CollisionKind::Crossing => "crossing",
+        };
+        let (first, second) = collision.guards;
+        let position = collision.position;
+        println!(
+            "  tick {}: guards {first} and {second}, {kind} at {},{}",
+            collision.tick, position.x, position.y
+        );
+    }
+    println!("Contended cells: {}", report.contended_cells.len());
+    println!("Coverage: {}", report.coverage);
+}
+
+fn run_optimization(
+    map_data: &MapData<'_>,
+    query: Query,
+    guard: usize,
+    executor: &Executor,
+    budget: Budget,
+) {
+    let report = |description: String| println!("Best so far: {description}");
+    let (best, is_complete) = match query {
+        Query::Trap => {
+            let describe = |trap: &[Coordinate]| {
+                let cells: Vec<String> = trap.iter().map(|&cell| format_coordinate(cell)).collect();
+                format!("{} obstructions at {}", trap.len(), cells.join(" "))
+            };
+            let SearchResult { best, is_complete } =
+                find_minimal_trap(map_data, guard, executor, budget, |trap| {
+                    report(describe(trap));
+                });
+            (best.map(|trap| describe(&trap)), is_complete)
+        }
+        Query::MostVisited | Query::FewestVisited => {
+            let goal = match query {
+                Query::MostVisited => Goal::MostVisited,
+                _ => Goal::FewestVisited,
+            };
+            let describe = |placement: &Placement| {
+                format!(
+                    "obstruction at {}, {} visited cells",
+                    format_coordinate(placement.obstruction),
+                    placement.nb_visited
+                )
+            };
+            let SearchResult { best, is_complete } =
+                find_placement(map_data, guard, goal, executor, budget, |placement| {
+                    report(describe(placement));
+                });
+            (best.as_ref().map(describe), is_complete)
+        }
+        Query::LongestCycle => {
+            let describe = |guard_loop: &GuardLoop| {
+                format!(
+                    "obstruction at {}, cycle of {} moves",
+                    format_coordinate(guard_loop.obstruction),
+                    guard_loop.cycle_length
+                )
+            };
+            let SearchResult { best, is_complete } =
+                find_longest_cycle(map_data, guard, executor, budget, |guard_loop| {
+                    report(describe(guard_loop));
+                });
+            (best.as_ref().map(describe), is_complete)
+        }
+    };
+
+    let best = best.unwrap_or_else(|| String::from("none"));
+    if is_complete {
+        println!("Best: {best}");
+    } else {
+        println!("Best: {best} (time budget exhausted)");
+    }
+}
+
+fn format_coordinate(coordinate: Coordinate) -> String {
+    format!("{},{}", coordinate.x, coordinate.y)
+}
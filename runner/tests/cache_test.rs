@@ -0,0 +1,111 @@
+use std::env;
+use std::path::PathBuf;
+
+fn get_temp_directory(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("aoc2024-cache-test-{}-{name}", std::process::id()))
+}
+
+mod cache_key {
+    use aoc2024_runner::cache::{get_solver_hash, hash_input, CacheKey};
+
+    #[test]
+    fn input_hash() {
+        // When
+        let result = ["", "a", "3   4\n"].map(hash_input);
+
+        // Then
+        assert_eq!(result[0], 0xcbf2_9ce4_8422_2325);
+        assert_eq!(result[1], 0xaf63_dc4c_8601_ec8c);
+        assert_ne!(result[2], hash_input("3   4"));
+    }
+
+    #[test]
+    fn solved_day() {
+        // When
+        let result = CacheKey::new(6, 2, "^");
+
+        // Then
+        assert_eq!(
+            result,
+            Some(CacheKey {
+                day: 6,
+                part: 2,
+                input_hash: hash_input("^"),
+                solver_hash: get_solver_hash(6).unwrap(),
+            })
+        );
+        assert_ne!(get_solver_hash(5), get_solver_hash(6));
+    }
+
+    #[test]
+    fn day_without_solver() {
+        // When
+        let result = CacheKey::new(25, 1, "");
+
+        // Then
+        assert_eq!(result, None);
+    }
+}
+
+mod answer_cache {
+    use super::get_temp_directory;
+    use aoc2024_runner::cache::{AnswerCache, CacheKey};
+
+    #[test]
+    fn answers() {
+        // Given
+        let cache = AnswerCache::new(get_temp_directory("answers"));
+        cache.insert_answers(1, "3   4\n", "11", "31").unwrap();
+
+        // When
+        let result = [
+            cache.get_answers(1, "3   4\n"),
+            cache.get_answers(1, "4   3\n"),
+            cache.get_answers(2, "3   4\n"),
+        ];
+
+        // Then
+        assert_eq!(
+            result,
+            [Some((String::from("11"), String::from("31"))), None, None]
+        );
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn other_solver() {
+        // Given
+        let cache = AnswerCache::new(get_temp_directory("other-solver"));
+        let key = CacheKey::new(3, 1, "mul(2,4)").unwrap();
+        cache.insert(&key, "8").unwrap();
+
+        // When
+        let result = cache.get(&CacheKey {
+            solver_hash: key.solver_hash ^ 1,
+            ..key
+        });
+
+        // Then
+        assert_eq!(result, None);
+        assert_eq!(cache.get(&key), Some(String::from("8")));
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn clear() {
+        // Given
+        let directory = get_temp_directory("clear");
+        let cache = AnswerCache::new(&directory);
+        cache.insert_answers(1, "3   4\n", "11", "31").unwrap();
+        cache.insert_answers(3, "mul(2,4)", "8", "8").unwrap();
+
+        // When
+        let result = cache.clear().unwrap();
+
+        // Then
+        assert_eq!(result, 4);
+        assert!(!directory.exists());
+        assert_eq!(cache.get_answers(1, "3   4\n"), None);
+        assert_eq!(cache.clear().unwrap(), 0);
+    }
+}
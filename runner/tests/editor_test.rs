@@ -0,0 +1,137 @@
+const LOOP_MAP: &str = ".#..\n...#\n....\n.^..\n..#.\n";
+
+mod from_text {
+    use super::LOOP_MAP;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_runner::editor::Editor;
+
+    #[test]
+    fn stats_of_the_loaded_map() {
+        // When
+        let editor = Editor::from_text(LOOP_MAP, Executor::sequential()).unwrap();
+
+        // Then
+        let stats = editor.get_stats().unwrap();
+        assert_eq!(stats.part_1, "7");
+        assert_eq!(stats.part_2, "1");
+        assert!(!stats.is_looping);
+        assert_eq!(editor.to_text(), LOOP_MAP);
+        assert!(!editor.is_modified());
+    }
+
+    #[test]
+    fn no_guard() {
+        // When
+        let editor = Editor::from_text("..\n..\n", Executor::sequential()).unwrap();
+
+        // Then
+        assert!(editor.get_stats().is_none());
+    }
+
+    #[test]
+    fn unpaired_teleporter() {
+        // When
+        let result = Editor::from_text("^.1\n.1.\n1..\n", Executor::sequential());
+
+        // Then
+        assert_eq!(
+            result.err().unwrap(),
+            "Teleporter 1 needs exactly two tiles, not 3"
+        );
+    }
+
+    #[test]
+    fn invalid_character() {
+        // When
+        let result = Editor::from_text("^.\n.x\n", Executor::sequential());
+
+        // Then
+        assert_eq!(result.err().unwrap(), "Invalid character x at 1,1");
+    }
+
+    #[test]
+    fn lines_of_different_lengths() {
+        // When
+        let result = Editor::from_text("^..\n..\n", Executor::sequential());
+
+        // Then
+        assert_eq!(
+            result.err().unwrap(),
+            "Line 2 is not as long as the first one"
+        );
+    }
+}
+
+mod move_cursor {
+    use aoc2024_common::exec::Executor;
+    use aoc2024_runner::editor::Editor;
+
+    #[test]
+    fn stays_in_the_map() {
+        // Given
+        let mut editor = Editor::new(3, 2, Executor::sequential());
+
+        // When
+        editor.move_cursor(-1, 5);
+
+        // Then
+        assert_eq!(editor.get_cursor(), (0, 1));
+    }
+}
+
+mod toggle_wall {
+    use super::LOOP_MAP;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_runner::editor::Editor;
+
+    #[test]
+    fn wall_trapping_the_guard() {
+        // Given
+        let mut editor = Editor::from_text(LOOP_MAP, Executor::sequential()).unwrap();
+        editor.move_cursor(0, 3);
+
+        // When
+        let is_toggled = editor.toggle_wall();
+
+        // Then
+        assert!(is_toggled);
+        assert!(editor.is_modified());
+        assert!(editor.get_stats().unwrap().is_looping);
+        assert_eq!(editor.to_text(), ".#..\n...#\n....\n#^..\n..#.\n");
+    }
+
+    #[test]
+    fn not_on_the_guard() {
+        // Given
+        let mut editor = Editor::from_text(LOOP_MAP, Executor::sequential()).unwrap();
+        editor.move_cursor(1, 3);
+
+        // When
+        let is_toggled = editor.toggle_wall();
+
+        // Then
+        assert!(!is_toggled);
+        assert_eq!(editor.to_text(), LOOP_MAP);
+    }
+}
+
+mod place_origin {
+    use super::LOOP_MAP;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_runner::editor::Editor;
+
+    #[test]
+    fn moves_the_origin() {
+        // Given
+        let mut editor = Editor::from_text(LOOP_MAP, Executor::sequential()).unwrap();
+        editor.move_cursor(3, 4);
+
+        // When
+        let is_placed = editor.place_origin();
+
+        // Then
+        assert!(is_placed);
+        assert_eq!(editor.to_text(), ".#..\n...#\n....\n....\n..#^\n");
+        assert_eq!(editor.get_stats().unwrap().part_1, "3");
+    }
+}
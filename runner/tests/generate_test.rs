@@ -0,0 +1,108 @@
+mod generate {
+    use aoc2024_common::exec::Executor;
+    use aoc2024_runner::generate::{generate, GenerateOptions};
+
+    #[test]
+    fn same_seed_same_input() {
+        // Given
+        let options = GenerateOptions {
+            seed: 12,
+            size: Some(20),
+            ..GenerateOptions::default()
+        };
+
+        // When
+        let first = generate(3, &options, &Executor::default());
+        let second = generate(3, &options, &Executor::default());
+
+        // Then
+        assert_eq!(first.lines, second.lines);
+        assert_eq!(first.part_1, second.part_1);
+    }
+
+    #[test]
+    fn day_01_has_requested_size() {
+        // Given
+        let options = GenerateOptions {
+            size: Some(25),
+            ..GenerateOptions::default()
+        };
+
+        // When
+        let result = generate(1, &options, &Executor::default());
+
+        // Then
+        assert_eq!(result.lines.len(), 25);
+    }
+
+    #[test]
+    fn day_02_safe_ratio() {
+        // Given
+        let options = GenerateOptions {
+            seed: 5,
+            size: Some(200),
+            safe_ratio: 0.3,
+            ..GenerateOptions::default()
+        };
+
+        // When
+        let result = generate(2, &options, &Executor::default());
+
+        // Then
+        assert_eq!(result.part_1, "60");
+    }
+
+    #[test]
+    fn day_04_planted_words_are_found() {
+        // Given
+        let options = GenerateOptions {
+            seed: 5,
+            size: Some(30),
+            planted: Some(20),
+            ..GenerateOptions::default()
+        };
+
+        // When
+        let result = generate(4, &options, &Executor::default());
+
+        // Then
+        assert_eq!(result.lines.len(), 30);
+        assert!(result.part_1.parse::<usize>().unwrap() > 0);
+        assert!(result.part_2.parse::<usize>().unwrap() > 0);
+    }
+
+    #[test]
+    fn day_05_rules_cover_all_pairs() {
+        // Given
+        let options = GenerateOptions {
+            size: Some(10),
+            ..GenerateOptions::default()
+        };
+
+        // When
+        let result = generate(5, &options, &Executor::default());
+
+        // Then
+        let nb_rules = result.lines.iter().filter(|x| x.contains('|')).count();
+        let nb_updates = result.lines.iter().filter(|x| x.contains(',')).count();
+        assert_eq!(nb_rules, 49 * 48 / 2);
+        assert_eq!(nb_updates, 10);
+    }
+
+    #[test]
+    fn day_06_reaches_loop_target() {
+        // Given
+        let options = GenerateOptions {
+            seed: 3,
+            size: Some(20),
+            loops: Some(15),
+            ..GenerateOptions::default()
+        };
+
+        // When
+        let result = generate(6, &options, &Executor::default());
+
+        // Then
+        assert_eq!(result.part_2, "15");
+    }
+}
@@ -0,0 +1,22 @@
+mod get_level {
+    use aoc2024_runner::logging::get_level;
+    use tracing_subscriber::filter::LevelFilter;
+
+    #[test]
+    fn more_details_with_each_flag() {
+        // When
+        let levels: Vec<LevelFilter> = (0..5).map(get_level).collect();
+
+        // Then
+        assert_eq!(
+            levels,
+            vec![
+                LevelFilter::WARN,
+                LevelFilter::INFO,
+                LevelFilter::DEBUG,
+                LevelFilter::TRACE,
+                LevelFilter::TRACE,
+            ]
+        );
+    }
+}
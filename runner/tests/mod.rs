@@ -0,0 +1,14 @@
+#![warn(clippy::pedantic)]
+
+pub mod cache_test;
+pub mod editor_test;
+pub mod generate_test;
+pub mod logging_test;
+pub mod progress_test;
+pub mod repl_test;
+pub mod rng_test;
+pub mod scaffold_test;
+pub mod serve_test;
+pub mod snapshots_test;
+pub mod solve_test;
+pub mod watch_test;
@@ -0,0 +1,95 @@
+mod progress_bar {
+    use aoc2024_common::exec::Executor;
+    use aoc2024_common::progress::ProgressHook;
+    use aoc2024_runner::progress::ProgressBar;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn redrawn_on_the_same_line() {
+        // Given
+        let progress_bar = Arc::new(ProgressBar::new(Vec::new(), Duration::ZERO));
+        let executor = Executor::sequential().with_progress_hook(progress_bar.clone());
+        let items: Vec<u32> = (0..3).collect();
+
+        // When
+        executor.start_phase("counting");
+        executor.count(&items, |_| true);
+        progress_bar.finish();
+        drop(executor);
+
+        // Then
+        let output = Arc::into_inner(progress_bar).unwrap().into_output();
+        let lines: Vec<String> = String::from_utf8(output)
+            .unwrap()
+            .split("\r\x1b[2K")
+            .map(String::from)
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                "",
+                "counting",
+                "counting [##########....................] 1/3",
+                "counting [####################..........] 2/3",
+                "counting [##############################] 3/3",
+                "",
+            ]
+        );
+    }
+}
+
+mod progress_log {
+    use aoc2024_common::exec::Executor;
+    use aoc2024_runner::progress::ProgressLog;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn one_line_per_phase_when_quick() {
+        // Given
+        let progress_log = Arc::new(ProgressLog::new(Vec::new(), Duration::from_mins(1)));
+        let executor = Executor::sequential().with_progress_hook(progress_log.clone());
+        let items: Vec<u32> = (0..100).collect();
+
+        // When
+        executor.start_phase("parsing");
+        executor.start_phase("summing");
+        executor.sum(&items, |&item| item);
+        drop(executor);
+
+        // Then
+        let output = Arc::into_inner(progress_log).unwrap().into_output();
+        let phases: Vec<String> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| line.split_once("] ").unwrap().1.to_string())
+            .collect();
+        assert_eq!(phases, vec!["parsing", "summing"]);
+    }
+
+    #[test]
+    fn periodic_lines() {
+        // Given
+        let progress_log = Arc::new(ProgressLog::new(Vec::new(), Duration::ZERO));
+        let executor = Executor::sequential().with_progress_hook(progress_log.clone());
+        let items: Vec<u32> = (0..2).collect();
+
+        // When
+        executor.start_phase("summing");
+        executor.sum(&items, |&item| item);
+        drop(executor);
+
+        // Then
+        let output = Arc::into_inner(progress_log).unwrap().into_output();
+        let phases: Vec<String> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| line.split_once("] ").unwrap().1.to_string())
+            .collect();
+        assert_eq!(
+            phases,
+            vec!["summing", "summing: 1/2 items", "summing: 2/2 items"]
+        );
+    }
+}
@@ -0,0 +1,157 @@
+const DAY_02_EXAMPLE: &str = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+
+const DAY_05_EXAMPLE: &str = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n\
+                              53|29\n61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n\
+                              47|29\n75|13\n53|13\n\n75,47,61,53,29\n97,61,53,29,13\n75,29,13\n\
+                              75,97,47,61,53\n61,13,29\n97,13,75,29,47\n";
+
+const DAY_06_EXAMPLE: &str = "....#.....\n.........#\n..........\n..#.......\n.......#..\n\
+                              ..........\n.#..^.....\n........#.\n#.........\n......#...\n";
+
+mod execute {
+    use super::{DAY_02_EXAMPLE, DAY_05_EXAMPLE, DAY_06_EXAMPLE};
+    use aoc2024_common::exec::Executor;
+    use aoc2024_runner::repl::Repl;
+
+    #[test]
+    fn parts_of_any_day() {
+        // Given
+        let mut repl = Repl::new(2, DAY_02_EXAMPLE, Executor::sequential());
+
+        // When
+        let part_1 = repl.execute("part1");
+        let part_2 = repl.execute("part2");
+
+        // Then
+        assert_eq!(part_1, Ok(String::from("2")));
+        assert_eq!(part_2, Ok(String::from("4")));
+    }
+
+    #[test]
+    fn reports_by_line() {
+        // Given
+        let mut repl = Repl::new(2, DAY_02_EXAMPLE, Executor::sequential());
+
+        // When
+        let levels = repl.execute("report 4");
+        let verdicts: Vec<_> = ["is_safe 1", "is_safe 4", "is_safe 2"]
+            .into_iter()
+            .map(|line| repl.execute(line))
+            .collect();
+
+        // Then
+        assert_eq!(levels, Ok(String::from("1 3 2 4 5")));
+        assert_eq!(
+            verdicts,
+            vec![
+                Ok(String::from("safe")),
+                Ok(String::from("safe with the dampener")),
+                Ok(String::from("unsafe")),
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_report() {
+        // Given
+        let mut repl = Repl::new(2, DAY_02_EXAMPLE, Executor::sequential());
+
+        // When
+        let results = [repl.execute("report 0"), repl.execute("report 7")];
+
+        // Then
+        assert_eq!(
+            results,
+            [
+                Err(String::from("No report at line 0")),
+                Err(String::from("No report at line 7")),
+            ]
+        );
+    }
+
+    #[test]
+    fn rules_and_updates() {
+        // Given
+        let mut repl = Repl::new(5, DAY_05_EXAMPLE, Executor::sequential());
+
+        // When
+        let rules = repl.execute("rules-for 53");
+        let update = repl.execute("update 4");
+
+        // Then
+        assert_eq!(
+            rules,
+            Ok(String::from("Before 53: 47,61,75,97\nAfter 53: 13,29"))
+        );
+        assert_eq!(
+            update,
+            Ok(String::from("75,97,47,61,53: incorrectly ordered"))
+        );
+    }
+
+    #[test]
+    fn walk_then_show() {
+        // Given
+        let mut repl = Repl::new(6, DAY_06_EXAMPLE, Executor::sequential());
+
+        // When
+        let walk = repl.execute("walk --obstruct 3,6");
+        let map = repl.execute("show").unwrap();
+
+        // Then
+        assert_eq!(
+            walk,
+            Ok(String::from(
+                "Guard 0 is stuck in a loop after visiting 18 cells"
+            ))
+        );
+        assert_eq!(map.lines().nth(6), Some(".#.O^XXXX."));
+    }
+
+    #[test]
+    fn invalid_commands() {
+        // Given
+        let mut repl = Repl::new(6, DAY_06_EXAMPLE, Executor::sequential());
+
+        // When
+        let results = [
+            repl.execute("is_safe 1"),
+            repl.execute("walk --guard 1"),
+            repl.execute("walk --obstruct 3"),
+        ];
+
+        // Then
+        assert_eq!(
+            results,
+            [
+                Err(String::from("Unknown command: is_safe, see help")),
+                Err(String::from("No guard 1")),
+                Err(String::from("Invalid position: 3")),
+            ]
+        );
+    }
+}
+
+mod run {
+    use super::DAY_02_EXAMPLE;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_runner::repl::{run, Repl};
+
+    #[test]
+    fn stops_at_quit() {
+        // Given
+        let mut repl = Repl::new(2, DAY_02_EXAMPLE, Executor::sequential());
+        let mut output = Vec::new();
+
+        // When
+        run(
+            &mut repl,
+            "\nis_safe 1\nquit\npart1\n".as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+
+        // Then
+        assert_eq!(String::from_utf8(output).unwrap(), "> > safe\n> ");
+    }
+}
@@ -0,0 +1,54 @@
+mod next_u64 {
+    use aoc2024_runner::rng::Rng;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        // Given
+        let mut first = Rng::new(42);
+        let mut second = Rng::new(42);
+
+        // When
+        let first_values: Vec<u64> = (0..10).map(|_| first.next_u64()).collect();
+        let second_values: Vec<u64> = (0..10).map(|_| second.next_u64()).collect();
+
+        // Then
+        assert_eq!(first_values, second_values);
+    }
+}
+
+mod between {
+    use aoc2024_runner::rng::Rng;
+
+    #[test]
+    fn stays_within_bounds() {
+        // Given
+        let mut rng = Rng::new(7);
+
+        // When
+        let values: Vec<i32> = (0..1000).map(|_| rng.between(-3, 3)).collect();
+
+        // Then
+        assert!(values.iter().all(|x| (-3..=3).contains(x)));
+        assert!(values.contains(&-3));
+        assert!(values.contains(&3));
+    }
+}
+
+mod shuffle {
+    use aoc2024_runner::rng::Rng;
+
+    #[test]
+    fn keeps_all_items() {
+        // Given
+        let mut rng = Rng::new(7);
+        let mut items: Vec<i32> = (0..50).collect();
+
+        // When
+        rng.shuffle(&mut items);
+
+        // Then
+        assert_ne!(items, (0..50).collect::<Vec<i32>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..50).collect::<Vec<i32>>());
+    }
+}
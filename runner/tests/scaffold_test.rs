@@ -0,0 +1,126 @@
+mod new_day {
+    use std::path::{Path, PathBuf};
+
+    use aoc2024_runner::scaffold::NewDay;
+
+    #[test]
+    fn directory_name() {
+        // Given
+        let new_day = NewDay::new(7, " Bridge Repair ");
+
+        // When
+        let result = new_day.get_directory_name();
+
+        // Then
+        assert_eq!(result, "day-07-Bridge_Repair");
+    }
+
+    #[test]
+    fn files() {
+        // Given
+        let new_day = NewDay::new(7, "Bridge Repair");
+
+        // When
+        let result = new_day.get_files();
+
+        // Then
+        let paths: Vec<PathBuf> = result.iter().map(|(path, _)| path.clone()).collect();
+        assert!(paths.contains(&PathBuf::from("src/lib/solver.rs")));
+        assert!(paths.contains(&PathBuf::from("tests/solver_test.rs")));
+        assert!(paths.contains(&PathBuf::from("puzzle_input.txt")));
+        assert!(result.contains(&(PathBuf::from(".gitignore"), String::from("/target\n"))));
+        let (_, solver) = result
+            .iter()
+            .find(|(path, _)| path == Path::new("src/lib/solver.rs"))
+            .unwrap();
+        assert!(solver.contains("impl Day for Day07 {"));
+        assert!(result.iter().all(|(_, content)| !content.contains("{NN}")));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid title: \"../Escape\"")]
+    fn invalid_title() {
+        // When
+        NewDay::new(7, "../Escape");
+    }
+}
+
+mod wire_runner_manifest {
+    use aoc2024_runner::scaffold::NewDay;
+
+    const MANIFEST: &str = "[dependencies]\n\
+                            clap = \"4.5\"\n\
+                            day01 = { package = \"aoc2024-day-01\", path = \"../day-01-A\" }\n\
+                            day03 = { package = \"aoc2024-day-03\", path = \"../day-03-C\" }\n\
+                            \n\
+                            [lib]\n";
+
+    #[test]
+    fn between_days() {
+        // Given
+        let new_day = NewDay::new(2, "B");
+
+        // When
+        let result = new_day.wire_runner_manifest(MANIFEST);
+
+        // Then
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(
+            lines[3],
+            "day02 = { package = \"aoc2024-day-02\", path = \"../day-02-B\" }"
+        );
+        assert_eq!(lines[4], MANIFEST.lines().nth(3).unwrap());
+    }
+
+    #[test]
+    fn after_the_last_day() {
+        // Given
+        let new_day = NewDay::new(7, "G");
+
+        // When
+        let result = new_day.wire_runner_manifest(MANIFEST);
+
+        // Then
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(
+            lines[4],
+            "day07 = { package = \"aoc2024-day-07\", path = \"../day-07-G\" }"
+        );
+        assert_eq!(lines[5], "");
+    }
+}
+
+mod wire_solve {
+    use aoc2024_runner::scaffold::NewDay;
+
+    #[test]
+    fn current_solve_module() {
+        // Given
+        let source = include_str!("../src/lib/solve.rs");
+        let new_day = NewDay::new(25, "Last");
+
+        // When
+        let result = new_day.wire_solve(source);
+
+        // Then
+        assert!(result.contains(
+            "    Solver::new::<day06::solver::Day06>(6),\n    Solver::new::<day25::solver::Day25>(25),\n];"
+        ));
+    }
+
+    #[test]
+    fn kept_in_order() {
+        // Given
+        let source = "pub const SOLVERS: &[Solver] = &[\n    Solver::new::<day01::solver::Day01>(1),\n    Solver::new::<day12::solver::Day12>(12),\n];\n";
+        let new_day = NewDay::new(7, "Between");
+
+        // When
+        let result = new_day.wire_solve(source);
+
+        // Then
+        assert_eq!(
+            result,
+            "pub const SOLVERS: &[Solver] = &[\n    Solver::new::<day01::solver::Day01>(1),\n    Solver::new::<day07::solver::Day07>(7),\n    Solver::new::<day12::solver::Day12>(12),\n];\n"
+        );
+    }
+}
@@ -0,0 +1,140 @@
+const DAY_02_EXAMPLE: &str = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+
+mod solve_day_part {
+    use super::DAY_02_EXAMPLE;
+    use aoc2024_runner::serve::{solve_day_part, SolveError};
+    use std::time::Duration;
+
+    #[test]
+    fn answer() {
+        // When
+        let result = solve_day_part(2, 2, DAY_02_EXAMPLE.to_string(), 1, Duration::from_secs(10));
+
+        // Then
+        assert_eq!(result.unwrap().answer, "4");
+    }
+
+    #[test]
+    fn parse_error() {
+        // When
+        let result = solve_day_part(6, 1, String::from("..\n.."), 1, Duration::from_secs(10));
+
+        // Then
+        assert_eq!(
+            result.unwrap_err(),
+            SolveError::Parse(String::from("No guard found in data"))
+        );
+    }
+
+    #[test]
+    fn timeout() {
+        // Given
+        let input = include_str!("../../day-06-Guard_Gallivant/puzzle_input.txt");
+
+        // When
+        let result = solve_day_part(6, 2, input.to_string(), 1, Duration::from_millis(1));
+
+        // Then
+        assert_eq!(result.unwrap_err(), SolveError::Timeout);
+    }
+}
+
+mod solve_server {
+    use super::DAY_02_EXAMPLE;
+    use aoc2024_runner::serve::{ServeOptions, SolveServer};
+    use std::io::{Read, Write};
+    use std::net::{SocketAddr, TcpStream};
+    use std::thread;
+
+    fn start_server(options: ServeOptions) -> SocketAddr {
+        let server = SolveServer::bind("127.0.0.1:0", options, 1).unwrap();
+        let address = server.get_address().unwrap();
+        thread::spawn(move || server.run());
+        address
+    }
+
+    /// Status code and body of the response
+    fn send(address: SocketAddr, method: &str, path: &str, body: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        (status, body.to_string())
+    }
+
+    #[test]
+    fn answer_and_timings() {
+        // Given
+        let address = start_server(ServeOptions::default());
+
+        // When
+        let (status, body) = send(address, "POST", "/solve/2/1", DAY_02_EXAMPLE);
+
+        // Then
+        assert_eq!(status, 200);
+        assert!(
+            body.starts_with("{\"day\": 2, \"part\": 1, \"answer\": \"2\", \"parse_time_ms\": ")
+        );
+        assert!(body.contains("\"solve_time_ms\": "));
+    }
+
+    #[test]
+    fn parse_error() {
+        // Given
+        let address = start_server(ServeOptions::default());
+
+        // When
+        let (status, body) = send(address, "POST", "/solve/6/1", "..\n..");
+
+        // Then
+        assert_eq!(status, 422);
+        assert_eq!(
+            body,
+            "{\"error\": \"Invalid input: No guard found in data\"}\n"
+        );
+    }
+
+    #[test]
+    fn input_too_large() {
+        // Given
+        let address = start_server(ServeOptions {
+            max_input_size: 10,
+            ..ServeOptions::default()
+        });
+
+        // When
+        let (status, body) = send(address, "POST", "/solve/2/1", DAY_02_EXAMPLE);
+
+        // Then
+        assert_eq!(status, 413);
+        assert_eq!(body, "{\"error\": \"The input is larger than 10 bytes\"}\n");
+    }
+
+    #[test]
+    fn invalid_requests() {
+        // Given
+        let address = start_server(ServeOptions::default());
+
+        // When
+        let statuses: Vec<u16> = [
+            ("GET", "/solve/2/1"),
+            ("POST", "/solve/7/1"),
+            ("POST", "/solve/2/3"),
+            ("POST", "/answers"),
+        ]
+        .into_iter()
+        .map(|(method, path)| send(address, method, path, DAY_02_EXAMPLE).0)
+        .collect();
+
+        // Then
+        assert_eq!(statuses, vec![405, 404, 404, 404]);
+    }
+}
@@ -0,0 +1,136 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// A workspace with a changed snapshot, a new one, and one in `target`
+fn create_workspace(name: &str) -> PathBuf {
+    let root = env::temp_dir().join(format!(
+        "aoc2024-snapshots-test-{}-{name}",
+        std::process::id()
+    ));
+    let snapshots = root.join("day-01-A/tests/snapshots");
+    fs::create_dir_all(&snapshots).unwrap();
+    fs::create_dir_all(root.join("target")).unwrap();
+    fs::write(
+        snapshots.join("parse.snap"),
+        "---\nexpression: result\n---\n[\n    1,\n]\n",
+    )
+    .unwrap();
+    fs::write(
+        snapshots.join("parse.snap.new"),
+        "---\nassertion_line: 12\nexpression: result\n---\n[\n    2,\n]\n",
+    )
+    .unwrap();
+    fs::write(
+        snapshots.join("walk.snap.new"),
+        "---\nexpression: result\n---\nX\n",
+    )
+    .unwrap();
+    fs::write(root.join("target/built.snap.new"), "").unwrap();
+    root
+}
+
+mod find_pending {
+    use super::create_workspace;
+    use aoc2024_runner::snapshots::{find_pending, PendingSnapshot};
+
+    #[test]
+    fn outside_of_target() {
+        // Given
+        let root = create_workspace("find");
+
+        // When
+        let result = find_pending(&root).unwrap();
+
+        // Then
+        let snapshots = root.join("day-01-A/tests/snapshots");
+        assert_eq!(
+            result,
+            [
+                PendingSnapshot {
+                    path: snapshots.join("parse.snap")
+                },
+                PendingSnapshot {
+                    path: snapshots.join("walk.snap")
+                },
+            ]
+        );
+    }
+}
+
+mod pending_snapshot {
+    use std::fs;
+
+    use super::create_workspace;
+    use aoc2024_runner::snapshots::find_pending;
+
+    #[test]
+    fn diff() {
+        // Given
+        let root = create_workspace("diff");
+        let pending = find_pending(&root).unwrap();
+
+        // When
+        let result = pending[0].get_diff().unwrap();
+
+        // Then, after the headers, three lines of context
+        let lines: Vec<&str> = result
+            .lines()
+            .filter(|line| !line.starts_with("@@"))
+            .collect();
+        assert_eq!(
+            &lines[2..],
+            [
+                " expression: result",
+                " ---",
+                " [",
+                "-    1,",
+                "+    2,",
+                " ]"
+            ]
+        );
+    }
+
+    #[test]
+    fn accept() {
+        // Given
+        let root = create_workspace("accept");
+        let pending = find_pending(&root).unwrap();
+
+        // When
+        for snapshot in &pending {
+            snapshot.accept().unwrap();
+        }
+
+        // Then
+        assert_eq!(
+            fs::read_to_string(&pending[0].path).unwrap(),
+            "---\nexpression: result\n---\n[\n    2,\n]\n"
+        );
+        assert_eq!(
+            fs::read_to_string(&pending[1].path).unwrap(),
+            "---\nexpression: result\n---\nX\n"
+        );
+        assert!(find_pending(&root).unwrap().is_empty());
+    }
+
+    #[test]
+    fn reject() {
+        // Given
+        let root = create_workspace("reject");
+        let pending = find_pending(&root).unwrap();
+
+        // When
+        for snapshot in &pending {
+            snapshot.reject().unwrap();
+        }
+
+        // Then
+        assert_eq!(
+            fs::read_to_string(&pending[0].path).unwrap(),
+            "---\nexpression: result\n---\n[\n    1,\n]\n"
+        );
+        assert!(!pending[1].path.exists());
+        assert!(find_pending(&root).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,106 @@
+mod solve_day {
+    use aoc2024_common::exec::Executor;
+    use aoc2024_runner::generate::{generate, GenerateOptions};
+    use aoc2024_runner::solve::{get_days, solve_day};
+
+    #[test]
+    fn same_answers_whatever_the_threads() {
+        // Given
+        let options = GenerateOptions {
+            seed: 21,
+            size: Some(40),
+            ..GenerateOptions::default()
+        };
+        let sequential = Executor::sequential();
+        let parallel = Executor::new(4);
+
+        for day in get_days() {
+            let input = generate(day, &options, &sequential).lines.join("\n");
+
+            // When
+            let sequential_answers = solve_day(day, &input, &sequential).unwrap();
+            let parallel_answers = solve_day(day, &input, &parallel).unwrap();
+
+            // Then
+            assert_eq!(sequential_answers.part_1, parallel_answers.part_1);
+            assert_eq!(sequential_answers.part_2, parallel_answers.part_2);
+        }
+    }
+}
+
+mod timeout {
+    use aoc2024_common::cancel::CancelToken;
+    use aoc2024_common::exec::Executor;
+    use aoc2024_common::progress::{Progress, ProgressHook};
+    use aoc2024_runner::generate::{generate, GenerateOptions};
+    use aoc2024_runner::solve::solve_day;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    /// Cancels after the first item of the given phase
+    struct CancelInPhase {
+        phase: &'static str,
+        is_in_phase: AtomicBool,
+        cancel_token: CancelToken,
+    }
+
+    impl ProgressHook for CancelInPhase {
+        fn start_phase(&self, phase: &str) {
+            self.is_in_phase
+                .store(phase == self.phase, Ordering::Relaxed);
+        }
+
+        fn update(&self, _progress: Progress) {
+            if self.is_in_phase.load(Ordering::Relaxed) {
+                self.cancel_token.cancel();
+            }
+        }
+    }
+
+    #[test]
+    fn cancelled_before_parsing() {
+        // Given
+        let input = "7 6 4 2 1\n1 2 7 8 9\n";
+        let cancel_token = CancelToken::new();
+        cancel_token.cancel();
+        let executor = Executor::sequential().with_cancel_token(cancel_token);
+
+        // When
+        let result = solve_day(2, input, &executor);
+
+        // Then
+        let timed_out = result.err().unwrap();
+        assert_eq!(timed_out.part, 0);
+        assert!(timed_out.parse_time.is_none());
+        assert!(timed_out.part_1.is_none());
+    }
+
+    #[test]
+    fn part_1_kept_when_part_2_times_out() {
+        // Given
+        let options = GenerateOptions {
+            size: Some(200),
+            ..GenerateOptions::default()
+        };
+        let input = generate(6, &options, &Executor::default()).lines.join("\n");
+        let cancel_token = CancelToken::new();
+        let progress_hook = Arc::new(CancelInPhase {
+            phase: "testing obstructions",
+            is_in_phase: AtomicBool::new(false),
+            cancel_token: cancel_token.clone(),
+        });
+        let executor = Executor::sequential()
+            .with_cancel_token(cancel_token)
+            .with_progress_hook(progress_hook);
+
+        // When
+        let result = solve_day(6, &input, &executor);
+
+        // Then
+        let timed_out = result.err().unwrap();
+        assert_eq!(timed_out.part, 2);
+        assert!(timed_out.part_1.is_some());
+        let progress = timed_out.progress;
+        assert!(progress.nb_done < progress.nb_items);
+    }
+}
@@ -0,0 +1,162 @@
+mod parse_duration {
+    use std::time::Duration;
+
+    use aoc2024_runner::watch::parse_duration;
+
+    #[test]
+    fn units() {
+        // When
+        let result = ["12.00ns", "201.54µs", "1.56ms", "2.50s"].map(parse_duration);
+
+        // Then
+        assert_eq!(
+            result,
+            [
+                Some(Duration::from_nanos(12)),
+                Some(Duration::from_nanos(201_540)),
+                Some(Duration::from_micros(1_560)),
+                Some(Duration::from_millis(2_500)),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        // When
+        let result = ["", "ms", "1.5h"].map(parse_duration);
+
+        // Then
+        assert_eq!(result, [None, None, None]);
+    }
+}
+
+mod parse_solve_output {
+    use std::time::Duration;
+
+    use aoc2024_runner::watch::{parse_solve_output, SolveOutput};
+
+    #[test]
+    fn solved() {
+        // Given
+        let output = "Parsed in: 10.00µs\nPart 1: 2583 (1.50ms)\nPart 2: 1978 (200.00µs)\n";
+
+        // When
+        let result = parse_solve_output(output);
+
+        // Then
+        assert_eq!(
+            result,
+            Some(SolveOutput {
+                parse_time: Duration::from_micros(10),
+                part_1: String::from("2583"),
+                part_1_time: Duration::from_micros(1_500),
+                part_2: String::from("1978"),
+                part_2_time: Duration::from_micros(200),
+            })
+        );
+    }
+
+    #[test]
+    fn with_allocations() {
+        // Given
+        let output = "Parsed in: 10.00µs [3 allocations, 1.50 KiB allocated, 1.00 KiB peak]\n\
+                      Part 1: 2583 (1.50ms) [0 allocations, 0 B allocated, 0 B peak]\n\
+                      Part 2: 1978 (200.00µs) [12 allocations, 96 B allocated, 48 B peak]\n";
+
+        // When
+        let result = parse_solve_output(output);
+
+        // Then
+        assert_eq!(
+            result,
+            Some(SolveOutput {
+                parse_time: Duration::from_micros(10),
+                part_1: String::from("2583"),
+                part_1_time: Duration::from_micros(1_500),
+                part_2: String::from("1978"),
+                part_2_time: Duration::from_micros(200),
+            })
+        );
+    }
+
+    #[test]
+    fn missing_part() {
+        // When
+        let result = parse_solve_output("Parsed in: 10.00µs\nPart 1: 2583 (1.50ms)\n");
+
+        // Then
+        assert_eq!(result, None);
+    }
+}
+
+mod parse_test_counts {
+    use aoc2024_runner::watch::{parse_test_counts, TestCounts};
+
+    #[test]
+    fn several_targets() {
+        // Given
+        let output = "test result: ok. 3 passed; 0 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.00s\n\
+                      test result: FAILED. 4 passed; 2 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.01s\n";
+
+        // When
+        let result = parse_test_counts(output);
+
+        // Then
+        assert_eq!(
+            result,
+            TestCounts {
+                passed: 7,
+                failed: 2,
+                ignored: 1
+            }
+        );
+    }
+}
+
+mod describe_changes {
+    use std::time::Duration;
+
+    use aoc2024_runner::watch::{describe_changes, SolveOutput};
+
+    fn output(part_1: &str, part_1_millis: u64) -> SolveOutput {
+        SolveOutput {
+            parse_time: Duration::from_millis(1),
+            part_1: String::from(part_1),
+            part_1_time: Duration::from_millis(part_1_millis),
+            part_2: String::from("7"),
+            part_2_time: Duration::from_millis(2),
+        }
+    }
+
+    #[test]
+    fn first_run() {
+        // When
+        let result = describe_changes(None, &output("41", 4));
+
+        // Then
+        assert_eq!(
+            result,
+            vec![
+                "Parsed in: 1.00ms",
+                "Part 1: 41 (4.00ms)",
+                "Part 2: 7 (2.00ms)"
+            ]
+        );
+    }
+
+    #[test]
+    fn changed_answer() {
+        // When
+        let result = describe_changes(Some(&output("41", 4)), &output("42", 3));
+
+        // Then
+        assert_eq!(
+            result,
+            vec![
+                "Parsed in: 1.00ms, +0%",
+                "Part 1: 41 -> 42 (3.00ms, -25%)",
+                "Part 2: 7, unchanged (2.00ms, +0%)"
+            ]
+        );
+    }
+}